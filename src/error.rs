@@ -0,0 +1,190 @@
+use super::i18n::{self, Lang};
+use seed::prelude::js_sys;
+use std::fmt;
+
+// GitHub APIの取得で起きるエラー。種類ごとに表示やリカバリー方法を変えられるように分けておく
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    // トークンにヘッダーとして送れない文字が含まれている
+    InvalidToken,
+    // 401。トークンが無効または期限切れ
+    Auth,
+    // SAML SSOが必要なOrganizationで、トークンがまだ認可されていない
+    Sso {
+        authorize_url: Option<String>,
+    },
+    // レート制限を使い切った。resetはUNIX時刻（秒）
+    RateLimit {
+        reset: Option<f64>,
+    },
+    // 短時間にリクエストを集中させたことによる二次レート制限。retry_atはRetry-Afterから求めたUNIX時刻（秒）
+    SecondaryRateLimit {
+        retry_at: f64,
+    },
+    NotFound {
+        url: String,
+    },
+    // リクエストを送れなかった、またはレスポンスを読み切れなかった
+    Network {
+        url: String,
+    },
+    // REQUEST_TIMEOUT_MSのうちにレスポンスが返ってこなかった
+    Timeout {
+        url: String,
+    },
+    Status {
+        url: String,
+        status: u16,
+        message: Option<String>,
+    },
+    Parse {
+        url: String,
+        kind: ParseError,
+        // プロキシのエラーページなど、想定外のレスポンスの原因を探れるようにステータスと本文の先頭を残す。
+        // キャッシュから読んだ内容の場合はステータスがない
+        status: Option<u16>,
+        body_snippet: String,
+    },
+    // Organizationは見つかったが、トークンで見られるリポジトリが1つもない
+    Empty,
+    // リポジトリやPRを読む権限がトークンにない。Fine-grainedトークンで対象や権限を付け忘れた場合に多い
+    InsufficientScope {
+        url: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    ContentType(String),
+    InvalidJson(String),
+    ExpectedArray,
+    ExpectedArrayButObject(Option<String>),
+    ExpectedObject,
+    UnexpectedItems(String),
+}
+
+impl FetchError {
+    // 時間をおいて同じリクエストをやり直せば成功する見込みがあるか
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            FetchError::RateLimit { .. }
+                | FetchError::SecondaryRateLimit { .. }
+                | FetchError::Network { .. }
+                | FetchError::Timeout { .. }
+                | FetchError::Status { .. }
+        )
+    }
+
+    // どのリポジトリに対しても同じように失敗するので、取得を続けても意味がないエラーか
+    pub fn affects_all_requests(&self) -> bool {
+        matches!(
+            self,
+            FetchError::InvalidToken
+                | FetchError::Auth
+                | FetchError::Sso { .. }
+                | FetchError::RateLimit { .. }
+                | FetchError::SecondaryRateLimit { .. }
+                | FetchError::InsufficientScope { .. }
+        )
+    }
+
+    // トークンを入れ直すか、権限を付け直したトークンに替えれば解決するエラーか
+    pub fn needs_new_token(&self) -> bool {
+        matches!(
+            self,
+            FetchError::InvalidToken
+                | FetchError::Auth
+                | FetchError::Empty
+                | FetchError::InsufficientScope { .. }
+        )
+    }
+
+    pub fn message(&self, lang: Lang) -> String {
+        let locale_time = |unix_secs: f64| {
+            js_sys::Date::new(&(unix_secs * 1000.0).into())
+                .to_locale_time_string(lang.code())
+                .as_string()
+                .unwrap_or_default()
+        };
+        match self {
+            FetchError::InvalidToken => i18n::invalid_token(lang).to_string(),
+            FetchError::Auth => i18n::bad_credentials(lang).to_string(),
+            FetchError::Sso { .. } => i18n::sso_required(lang).to_string(),
+            FetchError::RateLimit { reset } => {
+                i18n::rate_limited(lang, reset.map(locale_time).as_deref())
+            }
+            FetchError::SecondaryRateLimit { retry_at } => {
+                i18n::secondary_rate_limited(lang, &locale_time(*retry_at))
+            }
+            FetchError::NotFound { url } => i18n::not_found(lang, url),
+            FetchError::Network { url } => i18n::network_error(lang, url),
+            FetchError::Timeout { url } => i18n::request_timed_out(lang, url),
+            FetchError::Status {
+                url,
+                status,
+                message,
+            } => format!(
+                "{}: {}",
+                i18n::request_to_failed(lang, url),
+                i18n::unexpected_status(lang, *status, message.as_deref())
+            ),
+            FetchError::Parse {
+                url,
+                kind,
+                status,
+                body_snippet,
+            } => {
+                let status = status
+                    .map(|status| format!(" (HTTP {})", status))
+                    .unwrap_or_default();
+                format!(
+                    "{}: {}{}: {}",
+                    i18n::request_to_failed(lang, url),
+                    kind.message(lang),
+                    status,
+                    body_snippet
+                )
+            }
+            FetchError::Empty => i18n::no_repositories_found(lang).to_string(),
+            FetchError::InsufficientScope { url } => i18n::insufficient_scope(lang, url),
+        }
+    }
+}
+
+// 表示言語がわからない場所（ログなど）向けの英語の文言
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Lang::En))
+    }
+}
+
+// エラーメッセージに載せるレスポンス本文の最大文字数
+const BODY_SNIPPET_CHARS: usize = 200;
+
+pub fn body_snippet(body: &str) -> String {
+    let body = body.trim();
+    match body.char_indices().nth(BODY_SNIPPET_CHARS) {
+        Some((end, _)) => format!("{}…", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+impl ParseError {
+    fn message(&self, lang: Lang) -> String {
+        match self {
+            ParseError::ContentType(content_type) => {
+                i18n::unexpected_content_type(lang, content_type)
+            }
+            ParseError::InvalidJson(detail) => format!("{}: {}", i18n::invalid_json(lang), detail),
+            ParseError::ExpectedArray => i18n::expected_array(lang).to_string(),
+            ParseError::ExpectedArrayButObject(message) => {
+                i18n::expected_array_but_object(lang, message.as_deref())
+            }
+            ParseError::ExpectedObject => i18n::expected_object(lang).to_string(),
+            ParseError::UnexpectedItems(detail) => {
+                format!("{}: {}", i18n::unexpected_array_items(lang), detail)
+            }
+        }
+    }
+}
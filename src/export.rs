@@ -0,0 +1,140 @@
+use super::{board_repositories, board_rows, i18n, Model, Organization};
+
+// 端末やプレーンテキストのチャットに貼れるよう、等幅フォントで揃う表にする
+pub fn plain_text_table(organization: &Organization, model: &Model) -> String {
+    let repositories = board_repositories(organization, model);
+    let rows: Vec<Vec<String>> = board_rows(organization, &repositories, model)
+        .into_iter()
+        .map(|row| {
+            std::iter::once(organization.reviewer_label(row.owner, row.login))
+                .chain(row.cells.iter().map(|cell| cell.len().to_string()))
+                .collect()
+        })
+        .collect();
+    let header: Vec<String> = std::iter::once(i18n::reviewers(model.settings.lang).to_string())
+        .chain(
+            repositories
+                .iter()
+                .map(|repository| organization.repository_label(repository).to_string()),
+        )
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| display_width(&row[column]))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(column, (value, &width))| {
+                    let padding = " ".repeat(width - display_width(value));
+                    // 名前は左寄せ、件数は右寄せにする
+                    if column == 0 {
+                        format!("{}{}", value, padding)
+                    } else {
+                        format!("{}{}", padding, value)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// SlackやGitHubに貼れるMarkdownの表にする。PR番号はPRへのリンクにする
+pub fn markdown_table(organization: &Organization, model: &Model) -> String {
+    let repositories = board_repositories(organization, model);
+    let header: Vec<String> = std::iter::once(i18n::reviewers(model.settings.lang).to_string())
+        .chain(
+            repositories
+                .iter()
+                .map(|repository| organization.repository_label(repository).to_string()),
+        )
+        .collect();
+    let separator = vec!["---".to_string(); header.len()];
+    let rows: Vec<Vec<String>> = board_rows(organization, &repositories, model)
+        .into_iter()
+        .map(|row| {
+            std::iter::once(organization.reviewer_label(row.owner, row.login))
+                .chain(row.cells.iter().map(|cell| {
+                    cell.iter()
+                        .map(|pull_request| {
+                            format!("[#{}]({})", pull_request.number, pull_request.url)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }))
+                .collect()
+        })
+        .collect();
+    std::iter::once(&header)
+        .chain(std::iter::once(&separator))
+        .chain(&rows)
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(|value| value.replace('|', "\\|")).collect();
+            format!("| {} |", cells.join(" | "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 表計算ソフトで開けるCSVにする。各セルにはPR番号をカンマ区切りで入れる
+pub fn csv(organization: &Organization, model: &Model) -> String {
+    let repositories = board_repositories(organization, model);
+    let header: Vec<String> = std::iter::once(i18n::reviewers(model.settings.lang).to_string())
+        .chain(
+            repositories
+                .iter()
+                .map(|repository| organization.repository_label(repository).to_string()),
+        )
+        .collect();
+    let rows: Vec<Vec<String>> = board_rows(organization, &repositories, model)
+        .into_iter()
+        .map(|row| {
+            std::iter::once(organization.reviewer_label(row.owner, row.login))
+                .chain(row.cells.iter().map(|cell| {
+                    cell.iter()
+                        .map(|pull_request| pull_request.number.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }))
+                .collect()
+        })
+        .collect();
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            row.iter()
+                .map(|value| csv_field(value))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\r', '\n'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// 等幅フォントでの表示幅。ASCII以外は全角として2文字分で数える
+fn display_width(value: &str) -> usize {
+    value
+        .chars()
+        .map(|c| if c.is_ascii() { 1 } else { 2 })
+        .sum()
+}
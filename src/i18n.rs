@@ -0,0 +1,1153 @@
+use super::{ChipSort, CiStatus, Layout, PullRequestState, RepositorySort};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Lang {
+    #[default]
+    En,
+    Ja,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::En, Lang::Ja];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Ja => "ja",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        Lang::ALL
+            .iter()
+            .copied()
+            .find(|lang| code == lang.code() || code.starts_with(&format!("{}-", lang.code())))
+    }
+
+    // 言語切り替えの選択肢には、その言語自身の表記で名前を出す
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Lang::En => "English",
+            Lang::Ja => "日本語",
+        }
+    }
+}
+
+pub fn app_title(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "GitHub Organization Reviewers",
+        Lang::Ja => "GitHub Organization レビュアー一覧",
+    }
+}
+
+pub fn language(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Language",
+        Lang::Ja => "言語",
+    }
+}
+
+pub fn fetch_data(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetch data",
+        Lang::Ja => "データを取得",
+    }
+}
+
+pub fn organization(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Organization",
+        Lang::Ja => "Organization名",
+    }
+}
+
+pub fn organization_required(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Please enter an organization",
+        Lang::Ja => "Organizationを入力してください",
+    }
+}
+
+pub fn organization_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Separate several organizations with commas to view them with the same token.",
+        Lang::Ja => "カンマで区切ると、同じトークンで複数のOrganizationをまとめて表示します。",
+    }
+}
+
+pub fn switch_organization(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Switch organization…",
+        Lang::Ja => "Organizationを切り替える…",
+    }
+}
+
+pub fn unexpected_token_format(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "This doesn't look like a GitHub token (they usually start with ghp_ or github_pat_). Check that you pasted the whole token.",
+        Lang::Ja => "GitHubのトークンの形式ではないようです（通常はghp_またはgithub_pat_で始まります）。トークン全体を貼り付けたか確認してください。",
+    }
+}
+
+pub fn team_slug(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Team slug (optional)",
+        Lang::Ja => "チームのslug（任意）",
+    }
+}
+
+pub fn team_slug_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Only show repositories of this team. Leave empty for the whole organization.",
+        Lang::Ja => {
+            "このチームのリポジトリだけを表示します。空欄ならOrganization全体を表示します。"
+        }
+    }
+}
+
+pub fn repo_prefix(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Repository name prefix (optional)",
+        Lang::Ja => "リポジトリ名の先頭（任意）",
+    }
+}
+
+pub fn repo_prefix_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Only fetch PRs of repositories whose name starts with this, such as service-. Leave empty for all repositories.",
+        Lang::Ja => "service- のように、名前がこれで始まるリポジトリのPRだけを取得します。空欄ならすべてのリポジトリを対象にします。",
+    }
+}
+
+pub fn personal_access_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Personal access token",
+        Lang::Ja => "アクセストークン",
+    }
+}
+
+pub fn show_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Show",
+        Lang::Ja => "表示",
+    }
+}
+
+pub fn hide_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Hide",
+        Lang::Ja => "隠す",
+    }
+}
+
+pub fn clear_saved_credentials(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Clear saved credentials",
+        Lang::Ja => "保存した認証情報を消去",
+    }
+}
+
+pub fn token_expired(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => {
+            "Authentication failed — check your personal access token (it may be wrong or expired) and fetch again."
+        }
+        Lang::Ja => {
+            "認証に失敗しました。アクセストークンが間違っているか、有効期限が切れていないか確認して再取得してください。"
+        }
+    }
+}
+
+pub fn fetch_prompt(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Click the button to fetch data.",
+        Lang::Ja => "ボタンを押してデータを取得してください。",
+    }
+}
+
+pub fn loading(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Loading…",
+        Lang::Ja => "読み込み中…",
+    }
+}
+
+pub fn loading_pull_requests(lang: Lang, repository: &str, done: usize, total: usize) -> String {
+    match lang {
+        Lang::En => format!("Loading pulls for {} ({}/{})…", repository, done, total),
+        Lang::Ja => format!("{} のPRを読み込み中（{}/{}）…", repository, done, total),
+    }
+}
+
+pub fn pull_requests_awaiting_review(
+    lang: Lang,
+    pull_request_count: usize,
+    repository_count: usize,
+) -> String {
+    match lang {
+        Lang::En => format!(
+            "{} open {} awaiting review across {} {}",
+            pull_request_count,
+            if pull_request_count == 1 { "PR" } else { "PRs" },
+            repository_count,
+            if repository_count == 1 {
+                "repository"
+            } else {
+                "repositories"
+            }
+        ),
+        Lang::Ja => format!(
+            "{}個のリポジトリで{}件のPRがレビュー待ち",
+            repository_count, pull_request_count
+        ),
+    }
+}
+
+pub fn rate_limit_budget_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "GitHub API requests left in the current rate limit window",
+        Lang::Ja => "現在のレート制限の時間枠で、残っているGitHub APIのリクエスト数",
+    }
+}
+
+pub fn refresh(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Refresh",
+        Lang::Ja => "更新",
+    }
+}
+
+pub fn keyboard_shortcuts(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Keyboard shortcuts",
+        Lang::Ja => "キーボードショートカット",
+    }
+}
+
+pub fn shortcut_refresh(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Refresh",
+        Lang::Ja => "再取得する",
+    }
+}
+
+pub fn shortcut_filter_reviewers(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Filter reviewers",
+        Lang::Ja => "レビュアーを絞り込む",
+    }
+}
+
+pub fn shortcut_help(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Show or hide this help",
+        Lang::Ja => "このヘルプを表示する・閉じる",
+    }
+}
+
+pub fn refresh_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetch the latest data while keeping the current filters and scroll position",
+        Lang::Ja => "今の絞り込みやスクロール位置のまま、最新のデータを取得します",
+    }
+}
+
+pub fn force_refresh(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Force refresh",
+        Lang::Ja => "強制的に再取得",
+    }
+}
+
+pub fn force_refresh_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Ignore cached responses and fetch everything again",
+        Lang::Ja => "キャッシュを使わずに、すべて取得し直します",
+    }
+}
+
+pub fn last_updated(lang: Lang, age: &str) -> String {
+    match lang {
+        Lang::En => format!("Last updated {} ago", age),
+        Lang::Ja => format!("{}前に更新", age),
+    }
+}
+
+pub fn updated_just_now(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Updated just now",
+        Lang::Ja => "たった今更新",
+    }
+}
+
+pub fn refreshing(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Refreshing…",
+        Lang::Ja => "更新中…",
+    }
+}
+
+pub fn organization_label(lang: Lang, name: &str) -> String {
+    match lang {
+        Lang::En => format!("Organization: {}", name),
+        Lang::Ja => format!("組織: {}", name),
+    }
+}
+
+pub fn reviewers(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Reviewers",
+        Lang::Ja => "レビュアー",
+    }
+}
+
+pub fn repository(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Repository",
+        Lang::Ja => "リポジトリ",
+    }
+}
+
+pub fn all_repositories(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "All",
+        Lang::Ja => "すべて",
+    }
+}
+
+pub fn layout(lang: Lang, layout: Layout) -> &'static str {
+    match (lang, layout) {
+        (Lang::En, Layout::ByReviewer) => "Rows: reviewers",
+        (Lang::Ja, Layout::ByReviewer) => "行: レビュアー",
+        (Lang::En, Layout::ByRepository) => "Rows: repositories",
+        (Lang::Ja, Layout::ByRepository) => "行: リポジトリ",
+    }
+}
+
+pub fn repository_sort(lang: Lang, repository_sort: RepositorySort) -> &'static str {
+    match (lang, repository_sort) {
+        (Lang::En, RepositorySort::ApiOrder) => "Sort by recent push",
+        (Lang::Ja, RepositorySort::ApiOrder) => "最近pushされた順",
+        (Lang::En, RepositorySort::Alphabetical) => "Sort by name",
+        (Lang::Ja, RepositorySort::Alphabetical) => "名前順",
+        (Lang::En, RepositorySort::ByPullRequestCount) => "Sort by PR count",
+        (Lang::Ja, RepositorySort::ByPullRequestCount) => "PRの多い順",
+    }
+}
+
+pub fn chip_sort(lang: Lang, chip_sort: ChipSort) -> &'static str {
+    match (lang, chip_sort) {
+        (Lang::En, ChipSort::Oldest) => "PRs: oldest first",
+        (Lang::Ja, ChipSort::Oldest) => "PR: 古い順",
+        (Lang::En, ChipSort::Number) => "PRs: by number",
+        (Lang::Ja, ChipSort::Number) => "PR: 番号順",
+    }
+}
+
+pub fn filter_reviewers(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Filter reviewers",
+        Lang::Ja => "レビュアーを絞り込み",
+    }
+}
+
+pub fn min_prs(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Min. PRs",
+        Lang::Ja => "PRの件数が次以上",
+    }
+}
+
+pub fn total(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Total",
+        Lang::Ja => "合計",
+    }
+}
+
+pub fn assigned_pull_requests(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Open PRs assigned to at least one reviewer",
+        Lang::Ja => "レビュアーが割り当てられているオープンなPRの数",
+    }
+}
+
+pub fn capacity(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Max",
+        Lang::Ja => "上限",
+    }
+}
+
+pub fn capacity_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => {
+            "Maximum number of concurrent reviews for this reviewer. Leave empty for no limit."
+        }
+        Lang::Ja => "このレビュアーが同時に抱えられるレビュー数の上限。空欄で上限なし。",
+    }
+}
+
+pub fn needs_attention(lang: Lang, count: usize, stale_after_days: u32) -> String {
+    match lang {
+        Lang::En => format!(
+            "{} PRs need attention (open for {}+ days or failing CI). Click to show only these.",
+            count, stale_after_days
+        ),
+        Lang::Ja => format!(
+            "要対応のPRが{}件あります（{}日以上オープンかCIが失敗）。クリックでこれらのみ表示します。",
+            count, stale_after_days
+        ),
+    }
+}
+
+pub fn large_organization_threshold(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Ask before fetching more repositories than",
+        Lang::Ja => "この数を超えるリポジトリを取得する前に確認",
+    }
+}
+
+pub fn throttle(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Delay between repositories (ms)",
+        Lang::Ja => "リポジトリごとの取得の間隔（ミリ秒）",
+    }
+}
+
+pub fn throttle_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetch repositories one at a time with this pause in between to avoid secondary rate limits. 0 fetches several at once.",
+        Lang::Ja => "二次レート制限を避けるため、この間隔を空けて1つずつ取得します。0なら複数を同時に取得します。",
+    }
+}
+
+pub fn busy_threshold(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Highlight reviewers with more PRs than",
+        Lang::Ja => "担当PRがこの件数を超えたレビュアーを強調",
+    }
+}
+
+pub fn weighted_load(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Weighted load score",
+        Lang::Ja => "重み付きの負荷スコア",
+    }
+}
+
+pub fn draft_weight(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Draft PR weight",
+        Lang::Ja => "ドラフトPRの重み",
+    }
+}
+
+pub fn re_review_weight(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Re-review weight",
+        Lang::Ja => "再レビューの重み",
+    }
+}
+
+pub fn small_weight(lang: Lang, max_lines: u64) -> String {
+    match lang {
+        Lang::En => format!("Small PR weight (under {} changed lines)", max_lines),
+        Lang::Ja => format!("小さいPR（変更{}行未満）の重み", max_lines),
+    }
+}
+
+pub fn large_weight(lang: Lang, min_lines: u64) -> String {
+    match lang {
+        Lang::En => format!("Large PR weight ({}+ changed lines)", min_lines),
+        Lang::Ja => format!("大きいPR（変更{}行以上）の重み", min_lines),
+    }
+}
+
+pub fn ci_failure_weight(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Failing CI weight",
+        Lang::Ja => "CIが失敗しているPRの重み",
+    }
+}
+
+pub fn sort_by_weighted_load(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Sort reviewers by weighted load instead of PR count",
+        Lang::Ja => "レビュアーの並べ替えに、PR件数ではなく負荷スコアを使う",
+    }
+}
+
+pub fn label_color_rules(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Chip colors by label",
+        Lang::Ja => "ラベルごとのチップの色",
+    }
+}
+
+pub fn label_color_rules_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Comma-separated label=color pairs. A PR chip takes the color of the first rule whose label it has.",
+        Lang::Ja => "ラベル=色 をカンマ区切りで指定します。PRのチップには、付いているラベルに一致する最初のルールの色が付きます。",
+    }
+}
+
+pub fn sort_by_load(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Click to sort by load",
+        Lang::Ja => "クリックで負荷順に並べ替え",
+    }
+}
+
+pub fn auto_refresh(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Auto-refresh",
+        Lang::Ja => "自動更新",
+    }
+}
+
+pub fn off(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Off",
+        Lang::Ja => "オフ",
+    }
+}
+
+pub fn refresh_interval(lang: Lang, secs: u32) -> String {
+    match (lang, secs.is_multiple_of(60)) {
+        (Lang::En, true) => format!("Every {} min", secs / 60),
+        (Lang::En, false) => format!("Every {} s", secs),
+        (Lang::Ja, true) => format!("{}分ごと", secs / 60),
+        (Lang::Ja, false) => format!("{}秒ごと", secs),
+    }
+}
+
+pub fn review_sla_hours(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Review SLA (hours)",
+        Lang::Ja => "レビューSLA（時間）",
+    }
+}
+
+pub fn stale_days(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Stale after (days)",
+        Lang::Ja => "滞留とみなす日数",
+    }
+}
+
+pub fn sla_breaching(lang: Lang, count: usize, sla_hours: u32) -> String {
+    match lang {
+        Lang::En => format!("{} PRs breaching {}h SLA", count, sla_hours),
+        Lang::Ja => format!(
+            "{}時間のSLAを超過しているPRが{}件あります",
+            sla_hours, count
+        ),
+    }
+}
+
+pub fn compact_view(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Compact view",
+        Lang::Ja => "詰めて表示する",
+    }
+}
+
+pub fn hide_self_reviews(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Hide reviewers on their own PRs",
+        Lang::Ja => "自分のPRのレビュアーになっている場合は隠す",
+    }
+}
+
+pub fn hide_bots(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Hide bot reviewers",
+        Lang::Ja => "ボットのレビュアーを隠す",
+    }
+}
+
+pub fn extra_bot_logins(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Other bot logins",
+        Lang::Ja => "ほかにボットとして扱うユーザー",
+    }
+}
+
+pub fn extra_bot_logins_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Comma-separated logins to hide in addition to accounts ending in [bot], Dependabot, Renovate, GitHub Actions and Codecov.",
+        Lang::Ja => "[bot]で終わるアカウント、Dependabot、Renovate、GitHub Actions、Codecovのほかに隠すユーザーをカンマ区切りで指定します。",
+    }
+}
+
+pub fn self_reviews_hidden(lang: Lang, count: usize) -> String {
+    match lang {
+        Lang::En => format!("{} self-review entries hidden", count),
+        Lang::Ja => format!("自分のPRへのレビュー{}件を非表示にしています", count),
+    }
+}
+
+pub fn fetch_reviews(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Also show reviewers who already reviewed",
+        Lang::Ja => "レビュー済みのレビュアーも表示する",
+    }
+}
+
+pub fn fetch_reviews_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetches the reviews of every open PR, which adds one request per PR.",
+        Lang::Ja => {
+            "オープンなPRごとにレビュー一覧を取得するため、PRの数だけリクエストが増えます。"
+        }
+    }
+}
+
+pub fn fetch_mergeable(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Mark PRs with merge conflicts",
+        Lang::Ja => "コンフリクトしているPRに印を付ける",
+    }
+}
+
+pub fn fetch_mergeable_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetches the details of every open PR, which adds one request per PR.",
+        Lang::Ja => "オープンなPRごとに詳細を取得するため、PRの数だけリクエストが増えます。",
+    }
+}
+
+pub fn fetch_ci_status(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Show CI status",
+        Lang::Ja => "CIの結果を表示する",
+    }
+}
+
+pub fn show_all_members(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Show all organization members",
+        Lang::Ja => "Organizationのメンバー全員を表示する",
+    }
+}
+
+pub fn show_all_members_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Also fetches the member list so that members without review requests appear with zero PRs. Takes effect on the next fetch.",
+        Lang::Ja => "メンバー一覧も取得して、レビュー依頼のないメンバーもPR 0件として表示します。次回の取得から反映されます。",
+    }
+}
+
+pub fn fetch_ci_status_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Fetches the commit status of every open PR, which adds one request per PR.",
+        Lang::Ja => {
+            "オープンなPRごとにコミットのステータスを取得するため、PRの数だけリクエストが増えます。"
+        }
+    }
+}
+
+pub fn ci_status(lang: Lang, status: CiStatus) -> &'static str {
+    match (lang, status) {
+        (Lang::En, CiStatus::Success) => "CI passed",
+        (Lang::Ja, CiStatus::Success) => "CI成功",
+        (Lang::En, CiStatus::Pending) => "CI running",
+        (Lang::Ja, CiStatus::Pending) => "CI実行中",
+        (Lang::En, CiStatus::Failure) => "CI failed",
+        (Lang::Ja, CiStatus::Failure) => "CI失敗",
+    }
+}
+
+pub fn has_conflicts(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Has merge conflicts",
+        Lang::Ja => "コンフリクトがあります",
+    }
+}
+
+pub fn pull_request_state(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "PRs",
+        Lang::Ja => "対象のPR",
+    }
+}
+
+pub fn pull_request_state_option(lang: Lang, state: PullRequestState) -> &'static str {
+    match (lang, state) {
+        (Lang::En, PullRequestState::Open) => "Open",
+        (Lang::Ja, PullRequestState::Open) => "オープン",
+        (Lang::En, PullRequestState::Closed) => "Recently closed",
+        (Lang::Ja, PullRequestState::Closed) => "最近クローズされたもの",
+        (Lang::En, PullRequestState::All) => "Open and recently closed",
+        (Lang::Ja, PullRequestState::All) => "オープンと最近クローズされたもの",
+    }
+}
+
+pub fn use_assignees(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Count assignees as reviewers",
+        Lang::Ja => "Assigneeもレビュアーとして数える",
+    }
+}
+
+pub fn assignee(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Assignee",
+        Lang::Ja => "Assignee",
+    }
+}
+
+pub fn approved(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Approved",
+        Lang::Ja => "承認済み",
+    }
+}
+
+pub fn changes_requested(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Changes requested",
+        Lang::Ja => "変更を依頼済み",
+    }
+}
+
+pub fn include_archived(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Include archived repos",
+        Lang::Ja => "アーカイブ済みのリポジトリも含める",
+    }
+}
+
+// 日数の入力欄を挟んで表示する
+pub fn recent_only_before_days(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Only scan repos pushed within",
+        Lang::Ja => "直近",
+    }
+}
+
+pub fn recent_only_after_days(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "days",
+        Lang::Ja => "日以内にpushされたリポジトリのみPRを取得",
+    }
+}
+
+pub fn repositories_skipped(lang: Lang, count: usize, recent_days: u32) -> String {
+    match lang {
+        Lang::En => format!(
+            "Skipped {} repos with no pushes in the last {} days",
+            count, recent_days
+        ),
+        Lang::Ja => format!(
+            "直近{}日間にpushのないリポジトリ{}件をスキップしました",
+            recent_days, count
+        ),
+    }
+}
+
+pub fn some_requests_failed(lang: Lang, count: usize) -> String {
+    match lang {
+        Lang::En => format!(
+            "{} requests failed; the affected organizations are left out and the affected repos are shown empty.",
+            count
+        ),
+        Lang::Ja => format!(
+            "{}件の取得に失敗しました。失敗したOrganizationは表示せず、失敗したリポジトリは空のまま表示しています。",
+            count
+        ),
+    }
+}
+
+pub fn no_pending_reviews(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "No pending reviews!",
+        Lang::Ja => "レビュー待ちのPRはありません！",
+    }
+}
+
+pub fn dismiss(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Dismiss",
+        Lang::Ja => "閉じる",
+    }
+}
+
+pub fn open_all(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Open all",
+        Lang::Ja => "すべて開く",
+    }
+}
+
+pub fn open_all_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Open every PR assigned to this reviewer in new tabs",
+        Lang::Ja => "このレビュアーに割り当てられたPRをすべて新しいタブで開きます",
+    }
+}
+
+pub fn confirm_open_all(lang: Lang, count: usize) -> String {
+    match lang {
+        Lang::En => format!(
+            "Open {} tabs? Your browser may block some of them as pop-ups.",
+            count
+        ),
+        Lang::Ja => format!(
+            "{}個のタブを開きますか？ブラウザーによってはポップアップとしてブロックされます。",
+            count
+        ),
+    }
+}
+
+pub fn copy_plain_table(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Copy as plain text",
+        Lang::Ja => "プレーンテキストでコピー",
+    }
+}
+
+pub fn copy_markdown(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Copy as Markdown",
+        Lang::Ja => "Markdownでコピー",
+    }
+}
+
+pub fn export_csv(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Download CSV",
+        Lang::Ja => "CSVをダウンロード",
+    }
+}
+
+pub fn download_failed(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Could not download the file",
+        Lang::Ja => "ファイルをダウンロードできませんでした",
+    }
+}
+
+pub fn copy_link(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Copy link",
+        Lang::Ja => "リンクをコピー",
+    }
+}
+
+pub fn copied(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Copied!",
+        Lang::Ja => "コピーしました",
+    }
+}
+
+pub fn copy_failed(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Could not copy to the clipboard",
+        Lang::Ja => "クリップボードにコピーできませんでした",
+    }
+}
+
+pub fn watchlist(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Watchlist",
+        Lang::Ja => "ウォッチリスト",
+    }
+}
+
+pub fn add_to_watchlist(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Add to watchlist",
+        Lang::Ja => "ウォッチリストに追加",
+    }
+}
+
+pub fn remove_from_watchlist(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Remove from watchlist",
+        Lang::Ja => "ウォッチリストから外す",
+    }
+}
+
+pub fn unwatch(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Unwatch",
+        Lang::Ja => "ウォッチ解除",
+    }
+}
+
+// チップにマウスを載せたときに出す、PRの題名・作成者・経過時間
+pub fn pull_request_tooltip(lang: Lang, title: &str, author: &str, age: &str) -> String {
+    match lang {
+        Lang::En => format!("{}\nby {} · opened {} ago", title, author, age),
+        Lang::Ja => format!("{}\n作成者: {} ・ {}前に作成", title, author, age),
+    }
+}
+
+pub fn scroll_to_me(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Scroll to me",
+        Lang::Ja => "自分の行へ移動",
+    }
+}
+
+pub fn no_reviews_for_me(lang: Lang, login: &str) -> String {
+    match lang {
+        Lang::En => format!("No reviews are assigned to you ({}).", login),
+        Lang::Ja => format!("あなた（{}）に割り当てられたレビューはありません。", login),
+    }
+}
+
+pub fn unassigned(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "(unassigned)",
+        Lang::Ja => "（レビュアーなし）",
+    }
+}
+
+pub fn unassigned_hint(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Open PRs that nobody has been asked to review yet",
+        Lang::Ja => "まだ誰にもレビューを依頼していないオープンなPR",
+    }
+}
+
+pub fn no_reviewers_yet(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "No reviewers yet",
+        Lang::Ja => "レビュアーなし",
+    }
+}
+
+pub fn large_organization_warning(lang: Lang, repository_count: usize) -> String {
+    match lang {
+        Lang::En => format!(
+            "This org has {} repos; fetching all PRs may be slow and rate-limit heavy. Consider entering a team slug or showing only recently pushed repositories.",
+            repository_count
+        ),
+        Lang::Ja => format!(
+            "このOrganizationには{}個のリポジトリがあります。すべてのPRを取得すると時間がかかり、レート制限を大きく消費する可能性があります。チームのslugを指定するか、最近pushされたリポジトリだけに絞り込むことを検討してください。",
+            repository_count
+        ),
+    }
+}
+
+pub fn continue_fetch(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Continue",
+        Lang::Ja => "続行",
+    }
+}
+
+pub fn limit_to(lang: Lang, limit: usize) -> String {
+    match lang {
+        Lang::En => format!("Limit to {}", limit),
+        Lang::Ja => format!("{}件に制限", limit),
+    }
+}
+
+pub fn cancel(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Cancel",
+        Lang::Ja => "キャンセル",
+    }
+}
+
+pub fn request_to_failed(lang: Lang, url: &str) -> String {
+    match lang {
+        Lang::En => format!("Request to {} failed", url),
+        Lang::Ja => format!("{} へのリクエストが失敗しました", url),
+    }
+}
+
+pub fn retry(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Retry",
+        Lang::Ja => "再試行",
+    }
+}
+
+pub fn bad_credentials(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "GitHub rejected the token (HTTP 401: Bad credentials)",
+        Lang::Ja => "GitHubにトークンを拒否されました（HTTP 401: Bad credentials）",
+    }
+}
+
+pub fn invalid_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "The token contains characters that cannot be sent to GitHub",
+        Lang::Ja => "トークンにGitHubへ送信できない文字が含まれています",
+    }
+}
+
+pub fn sso_required(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => {
+            "This organization requires SAML SSO. Authorize your token for it and try again."
+        }
+        Lang::Ja => {
+            "このOrganizationではSAML SSOが必要です。トークンを認可してから再取得してください。"
+        }
+    }
+}
+
+pub fn authorize_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Authorize token",
+        Lang::Ja => "トークンを認可する",
+    }
+}
+
+pub fn secondary_rate_limited(lang: Lang, retry_at: &str) -> String {
+    match lang {
+        Lang::En => format!(
+            "GitHub asked to slow down (secondary rate limit). Retrying automatically at {}.",
+            retry_at
+        ),
+        Lang::Ja => format!(
+            "リクエストが集中したため、GitHubの二次レート制限に掛かりました。{}に自動で再試行します。",
+            retry_at
+        ),
+    }
+}
+
+pub fn rate_limited(lang: Lang, reset: Option<&str>) -> String {
+    match (lang, reset) {
+        (Lang::En, Some(reset)) => {
+            format!("GitHub API rate limit exceeded. It resets at {}.", reset)
+        }
+        (Lang::En, None) => "GitHub API rate limit exceeded.".to_string(),
+        (Lang::Ja, Some(reset)) => format!(
+            "GitHub APIのレート制限に達しました。{}にリセットされます。",
+            reset
+        ),
+        (Lang::Ja, None) => "GitHub APIのレート制限に達しました。".to_string(),
+    }
+}
+
+pub fn no_repositories_found(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "No repositories were found. Check that the token has access to the organization's repositories.",
+        Lang::Ja => "リポジトリが見つかりません。トークンにOrganizationのリポジトリへのアクセス権があるか確認してください。",
+    }
+}
+
+pub fn insufficient_scope(lang: Lang, url: &str) -> String {
+    match lang {
+        Lang::En => format!(
+            "The token may lack read access to repositories or pull requests of this organization ({}). For a fine-grained token, select the organization as the resource owner and grant \"Pull requests: Read-only\" to its repositories.",
+            url
+        ),
+        Lang::Ja => format!(
+            "トークンにこのOrganizationのリポジトリやPRを読む権限がない可能性があります（{}）。Fine-grainedトークンの場合は、リソースオーナーにOrganizationを選び、リポジトリに「Pull requests: Read-only」の権限を付けてください。",
+            url
+        ),
+    }
+}
+
+pub fn re_enter_token(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Re-enter token",
+        Lang::Ja => "トークンを入力し直す",
+    }
+}
+
+pub fn not_found(lang: Lang, url: &str) -> String {
+    match lang {
+        Lang::En => format!(
+            "{} was not found. Check the organization name and that the token can access it.",
+            url
+        ),
+        Lang::Ja => format!(
+            "{} が見つかりません。Organization名と、トークンにアクセス権があるかを確認してください。",
+            url
+        ),
+    }
+}
+
+pub fn request_timed_out(lang: Lang, url: &str) -> String {
+    match lang {
+        Lang::En => format!("Request timed out ({}). GitHub may be slow right now.", url),
+        Lang::Ja => format!(
+            "リクエストがタイムアウトしました（{}）。GitHubの応答が遅くなっている可能性があります。",
+            url
+        ),
+    }
+}
+
+pub fn network_error(lang: Lang, url: &str) -> String {
+    match lang {
+        Lang::En => format!("Could not reach GitHub ({}). Check your connection.", url),
+        Lang::Ja => format!(
+            "GitHubに接続できませんでした（{}）。ネットワーク接続を確認してください。",
+            url
+        ),
+    }
+}
+
+pub fn unexpected_status(lang: Lang, status: u16, message: Option<&str>) -> String {
+    match (lang, message) {
+        (Lang::En, Some(message)) => format!("GitHub responded with HTTP {}: {}", status, message),
+        (Lang::En, None) => format!("GitHub responded with HTTP {}", status),
+        (Lang::Ja, Some(message)) => format!("GitHubがHTTP {}を返しました: {}", status, message),
+        (Lang::Ja, None) => format!("GitHubがHTTP {}を返しました", status),
+    }
+}
+
+pub fn unexpected_content_type(lang: Lang, content_type: &str) -> String {
+    match lang {
+        Lang::En => format!("Expected a JSON response but got '{}'", content_type),
+        Lang::Ja => format!(
+            "JSONのレスポンスを想定していましたが '{}' でした",
+            content_type
+        ),
+    }
+}
+
+pub fn invalid_json(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "The response is not valid JSON",
+        Lang::Ja => "レスポンスが正しいJSONではありません",
+    }
+}
+
+pub fn expected_array(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Expected a JSON array in the response",
+        Lang::Ja => "レスポンスがJSON配列ではありません",
+    }
+}
+
+pub fn expected_object(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "Expected a JSON object in the response",
+        Lang::Ja => "レスポンスがJSONオブジェクトではありません",
+    }
+}
+
+pub fn expected_array_but_object(lang: Lang, message: Option<&str>) -> String {
+    match (lang, message) {
+        (Lang::En, Some(message)) => {
+            format!("Expected a JSON array but got an object: {}", message)
+        }
+        (Lang::En, None) => "Expected a JSON array but got an object".to_string(),
+        (Lang::Ja, Some(message)) => {
+            format!(
+                "JSON配列を想定していましたがオブジェクトでした: {}",
+                message
+            )
+        }
+        (Lang::Ja, None) => "JSON配列を想定していましたがオブジェクトでした".to_string(),
+    }
+}
+
+pub fn unexpected_array_items(lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => "The array items do not have the expected shape",
+        Lang::Ja => "配列の要素が想定した形式ではありません",
+    }
+}
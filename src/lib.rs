@@ -1,43 +1,1139 @@
 #![allow(clippy::wildcard_imports)]
-use anyhow::{Context, Result};
 use reqwest::header::{self, HeaderMap};
+use seed::futures::{
+    future::{self, Either},
+    stream, StreamExt, TryStreamExt,
+};
 use seed::{prelude::*, *};
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    ops::Range,
+    rc::Rc,
+};
+
+mod error;
+mod export;
+mod i18n;
+use error::{FetchError, ParseError};
+use i18n::Lang;
+
+const ORGANIZATION_STORAGE_KEY: &str = "organization";
+const TOKEN_STORAGE_KEY: &str = "token";
+const TEAM_SLUG_STORAGE_KEY: &str = "team_slug";
+const REPO_PREFIX_STORAGE_KEY: &str = "repo_prefix";
+const ORGANIZATION_HISTORY_STORAGE_KEY: &str = "org_history";
+// 入力候補に出す、最近取得したOrganizationの数
+const ORGANIZATION_HISTORY_LIMIT: usize = 8;
+const WATCHED_PULL_REQUESTS_STORAGE_KEY: &str = "watched_pull_requests";
+const SETTINGS_STORAGE_KEY: &str = "settings";
+const LAST_FETCH_STORAGE_KEY: &str = "last_fetch";
+// 共有できるリンクにするためのURLのクエリのキー
+const URL_ORGANIZATION_KEY: &str = "org";
+const URL_REPOSITORY_KEY: &str = "repo";
+const URL_REVIEWER_FILTER_KEY: &str = "filter";
+const URL_LAYOUT_KEY: &str = "layout";
+// レビュアーごとの上限はOrganizationごとに保存する
+const CAPACITIES_STORAGE_KEY_PREFIX: &str = "capacities:";
 
 // TODO: 諸々、利用する各Github APIのレスポンスを確認してデータ構造の改善をする
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Repository {
     name: String,
-    reviewers: Vec<Reviewer>, // TODO: レスポンスにない値なのでDeserialize失敗してるかも。要確認
+    // "owner/name"。複数のOrganizationを並べると名前が重なりうるので、列の識別にはこちらを使う
+    full_name: String,
+    // 一度もpushされていないリポジトリではnullになる
+    pushed_at: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    // レスポンスにない値なのでデフォルトで空にしておき、PR取得時に埋める
+    #[serde(default)]
+    pull_requests: Vec<PullRequest>,
+    // loginをキーにする。PRごとに引くので線形探索を避けつつ、見つかった順を保つ。
+    // seedのマクロが使うIndexMapとは版が違うので、名前をインポートせずに書く
+    #[serde(default)]
+    reviewers: indexmap::IndexMap<String, Reviewer>,
+}
+
+impl Repository {
+    fn owner(&self) -> &str {
+        self.full_name.split('/').next().unwrap_or_default()
+    }
+
+    fn reviewer(&self, name: &str) -> Option<&Reviewer> {
+        self.reviewers.get(name)
+    }
+
+    fn pull_request(&self, id: u64) -> Option<&PullRequest> {
+        self.pull_requests
+            .iter()
+            .find(|pull_request| pull_request.id == id)
+    }
+
+    fn assigned_pull_requests<'a>(
+        &'a self,
+        reviewer: &'a Reviewer,
+    ) -> impl Iterator<Item = &'a PullRequest> {
+        reviewer
+            .assigned_pull_request_ids
+            .iter()
+            .filter_map(move |&id| self.pull_request(id))
+    }
+
+    // レビュアーにPRを割り当てる。すでに割り当て済みの場合はfalseを返す
+    fn assign(&mut self, name: &str, id: u64) -> bool {
+        let reviewer = self
+            .reviewers
+            .entry(name.to_string())
+            .or_insert_with(|| Reviewer {
+                name: name.to_string(),
+                assigned_pull_request_ids: Vec::new(),
+                re_reviewed_pull_requests: Vec::new(),
+                review_states: Vec::new(),
+                assignee_pull_requests: Vec::new(),
+            });
+        if reviewer.assigned_pull_request_ids.contains(&id) {
+            return false;
+        }
+        reviewer.assigned_pull_request_ids.push(id);
+        true
+    }
+
+    fn set_review_state(&mut self, name: &str, id: u64, state: ReviewState) {
+        if let Some(reviewer) = self.reviewers.get_mut(name) {
+            reviewer
+                .review_states
+                .retain(|&(reviewed_id, _)| reviewed_id != id);
+            reviewer.review_states.push((id, state));
+        }
+    }
+
+    fn mark_assignee(&mut self, name: &str, id: u64) {
+        if let Some(reviewer) = self.reviewers.get_mut(name) {
+            reviewer.assignee_pull_requests.push(id);
+        }
+    }
+
+    fn mark_re_reviewed(&mut self, name: &str, id: u64) {
+        if let Some(reviewer) = self.reviewers.get_mut(name) {
+            if !reviewer.re_reviewed_pull_requests.contains(&id) {
+                reviewer.re_reviewed_pull_requests.push(id);
+            }
+        }
+    }
+
+    // 誰かにレビューが割り当てられているPRのid。複数人に割り当てられたPRも1件と数える。
+    // クローズ済みのPRはもうレビューを待っていないので数えない。
+    // レビュアーのいないPRをまとめる行は実在のレビュアーではないので、そこにしかないPRも数えない
+    fn assigned_pull_request_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .reviewers
+            .values()
+            .filter(|reviewer| reviewer.name != UNASSIGNED_REVIEWER)
+            .flat_map(|reviewer| &reviewer.assigned_pull_request_ids)
+            .copied()
+            .filter(|&id| {
+                self.pull_request(id)
+                    .is_none_or(|pull_request| !pull_request.closed)
+            })
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    fn assigned_pull_request_count(&self) -> usize {
+        self.assigned_pull_request_ids().len()
+    }
+
+    fn attention_count(&self, stale_days: u32) -> usize {
+        self.pull_requests
+            .iter()
+            .filter(|pull_request| needs_attention(pull_request, stale_days))
+            .count()
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Reviewer {
+    // GitHubのlogin。表の行はこの値で識別するので、表示名を取得しても表示にだけ使うこと
     name: String,
-    assigned_pull_requests: Vec<String>,
+    // PR本体はRepository::pull_requestsに1件ずつ持ち、ここではidだけを持つ
+    assigned_pull_request_ids: Vec<u64>,
+    // 同じPRに2回以上レビューしている（再レビュー）PRのid
+    #[serde(default)]
+    re_reviewed_pull_requests: Vec<u64>,
+    // レビュー依頼はもう残っていないが、レビュー済みのままオープンしているPRのidと最新のレビュー結果
+    #[serde(default)]
+    review_states: Vec<(u64, ReviewState)>,
+    // レビュー依頼ではなく、Assigneeとしてだけ割り当てられているPRのid
+    #[serde(default)]
+    assignee_pull_requests: Vec<u64>,
+}
+
+// 承認や変更依頼をすると、GitHubのrequested_reviewersからは外れる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum ReviewState {
+    Approved,
+    ChangesRequested,
 }
 
-#[derive(Debug)]
+impl Reviewer {
+    fn review_state(&self, id: u64) -> Option<ReviewState> {
+        self.review_states
+            .iter()
+            .find(|&&(reviewed_id, _)| reviewed_id == id)
+            .map(|&(_, state)| state)
+    }
+
+    fn load_weight(&self, pull_request: &PullRequest, weights: &LoadWeights) -> f64 {
+        let mut weight = 1.0;
+        if pull_request.draft {
+            weight *= weights.draft;
+        }
+        if self.re_reviewed_pull_requests.contains(&pull_request.id) {
+            weight *= weights.re_review;
+        }
+        match pull_request.changed_lines {
+            Some(lines) if lines < SMALL_PULL_REQUEST_MAX_LINES => weight *= weights.small,
+            Some(lines) if lines >= LARGE_PULL_REQUEST_MIN_LINES => weight *= weights.large,
+            _ => {}
+        }
+        if pull_request.ci_status == Some(CiStatus::Failure) {
+            weight *= weights.ci_failure;
+        }
+        weight
+    }
+}
+
+// 負荷スコアを計算するときの、条件ごとのPR1件あたりの重み。複数当てはまる場合は掛け合わせる。
+// サイズはPRの詳細を、CIの状態はステータスを取得しているときだけ効く
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct LoadWeights {
+    draft: f64,
+    re_review: f64,
+    // 変更行数がSMALL_PULL_REQUEST_MAX_LINES未満のPR
+    small: f64,
+    // 変更行数がLARGE_PULL_REQUEST_MIN_LINES以上のPR
+    large: f64,
+    ci_failure: f64,
+}
+
+// すべて1ならスコアは件数と同じになる
+impl Default for LoadWeights {
+    fn default() -> Self {
+        LoadWeights {
+            draft: 1.0,
+            re_review: 1.0,
+            small: 1.0,
+            large: 1.0,
+            ci_failure: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PullRequest {
+    // PR番号はリポジトリごとに重複するので、識別にはGitHub全体で一意なidを使う
+    id: u64,
+    number: u64,
+    title: String,
+    repo_name: String,
+    url: String,
+    author: String,
+    created_at: String,
+    draft: bool,
+    // クローズ済み（マージ済みを含む）
+    #[serde(default)]
+    closed: bool,
+    // コンフリクトなくマージできるか。取得していないか、GitHub側でまだ判定中ならNone
+    #[serde(default)]
+    mergeable: Option<bool>,
+    // headのコミットSHA。CIの結果を取得するのに使う
+    #[serde(default)]
+    head_sha: String,
+    // 取得していないか、CIが設定されていないリポジトリならNone
+    #[serde(default)]
+    ci_status: Option<CiStatus>,
+    // 追加と削除の行数の合計。一覧のAPIには含まれないので、PRの詳細を取得したときだけ入る
+    #[serde(default)]
+    changed_lines: Option<u64>,
+    // (ラベル名, 16進数の色)
+    #[serde(default)]
+    labels: Vec<(String, String)>,
+}
+
+// 1つのトークンで取得した、1つ以上のOrganizationのデータをまとめたもの
+#[derive(Debug, Serialize, Deserialize)]
 struct Organization {
-    name: String,
+    names: Vec<String>,
     repositories: Vec<Repository>,
+    // 担当PRがなくても行を出すOrganizationのメンバーの (owner, login)。show_all_membersのときだけ取得する
+    #[serde(default)]
+    members: Vec<(String, String)>,
+    // PRを取得できなかったリポジトリのエラー。そのリポジトリは空のまま表示を続ける
+    #[serde(skip)]
+    warnings: Vec<FetchError>,
+}
+
+impl Organization {
+    fn is_multiple(&self) -> bool {
+        self.names.len() > 1
+    }
+
+    // 表の行に使う (owner, login)。複数リポジトリに同じ人がいても1行にまとめるが、
+    // 無関係なOrganization同士でloginが重なることもあるので、Organizationごとには分ける
+    fn reviewer_keys(&self) -> Vec<(&str, &str)> {
+        let mut keys: Vec<(&str, &str)> = Vec::new();
+        for repository in &self.repositories {
+            for reviewer in repository.reviewers.values() {
+                let key = (repository.owner(), reviewer.name.as_str());
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    }
+
+    fn reviewer_label(&self, owner: &str, login: &str) -> String {
+        if self.is_multiple() {
+            format!("{} ({})", login, owner)
+        } else {
+            login.to_string()
+        }
+    }
+
+    fn repository_label<'a>(&self, repository: &'a Repository) -> &'a str {
+        if self.is_multiple() {
+            &repository.full_name
+        } else {
+            &repository.name
+        }
+    }
+
+    fn owners(&self) -> Vec<&str> {
+        let mut owners: Vec<&str> = Vec::new();
+        for repository in &self.repositories {
+            if !owners.contains(&repository.owner()) {
+                owners.push(repository.owner());
+            }
+        }
+        owners
+    }
+
+    // レビュー待ちのPRの数。複数人に割り当てられたPRも1件と数える
+    fn unique_pr_count(&self) -> usize {
+        self.repositories
+            .iter()
+            .map(Repository::assigned_pull_request_count)
+            .sum()
+    }
+
+    // レビュー待ちのPRが1件以上あるリポジトリの数
+    fn repositories_awaiting_review_count(&self) -> usize {
+        self.repositories
+            .iter()
+            .filter(|repository| repository.assigned_pull_request_count() > 0)
+            .count()
+    }
+
+    fn find_pull_request(&self, id: u64) -> Option<&PullRequest> {
+        self.repositories
+            .iter()
+            .flat_map(|repository| &repository.pull_requests)
+            .find(|pull_request| pull_request.id == id)
+    }
+
+    fn reviewer_names_of(&self, id: u64) -> Vec<&str> {
+        self.repositories
+            .iter()
+            .flat_map(|repository| repository.reviewers.values())
+            // レビュアーのいないPRをまとめる行は、実在のレビュアーではないので数えない
+            .filter(|reviewer| {
+                reviewer.name != UNASSIGNED_REVIEWER
+                    && reviewer.assigned_pull_request_ids.contains(&id)
+            })
+            .map(|reviewer| reviewer.name.as_str())
+            .collect()
+    }
+}
+
+// 再読み込み直後にすぐ表示できるよう、最後に取得できたデータを取得時刻と一緒に保存しておく
+#[derive(Serialize, Deserialize)]
+struct LastFetch {
+    fetched_at: String,
+    organization: Organization,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Form {
+    // カンマ区切りで複数のOrganizationを指定できる。トークンはすべてで共通
+    organization: String,
+    token: String,
+    // 指定するとOrganization全体ではなく、このチームのリポジトリだけを対象にする。空なら全体
+    team_slug: String,
+    // 名前がこれで始まるリポジトリだけPRを取得する（大文字小文字は区別しない）。空なら全部
+    repo_prefix: String,
+}
+
+impl Form {
+    fn organization_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for name in self.organization.split(',').map(str::trim) {
+            if !name.is_empty() && !names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+// 再読み込みしても残す表示や取得の設定。項目が増えても古い保存内容を読めるよう、
+// 保存されていない項目は既定値にする
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    lang: Lang,
+    // 自動更新の間隔。Noneなら自動更新しない
+    refresh_secs: Option<u32>,
+    // この時間を超えてオープンしているPRはレビューSLA超過として強調する
+    sla_hours: u32,
+    // 作成からこの日数が経ったPRは滞留しているとみなす
+    stale_days: u32,
+    // レビュアーがPRの作成者自身になっているチップを隠す（自動化などによるノイズ）
+    hide_self_reviews: bool,
+    // Dependabotなどのボットをレビュアーとして数えない。ボットにだけ依頼されたPRはレビュアーなしの行に入る
+    hide_bots: bool,
+    // KNOWN_BOT_LOGINSのほかにボットとして扱うlogin（カンマ区切り）
+    extra_bot_logins: String,
+    // 個別に上限を設定していないレビュアーについて、これを超えたら赤く強調する件数
+    busy_threshold: u32,
+    // これを超えるリポジトリ数の場合は、全リポジトリのPRを取得する前に確認する
+    large_organization_threshold: usize,
+    // 二次レート制限に掛からないよう、リポジトリごとの取得の間をこの時間（ミリ秒）空ける。0なら空けない
+    throttle_ms: u32,
+    // アーカイブ済みのリポジトリにはレビューすべきPRがないので、普段はPRを取得しない
+    include_archived: bool,
+    // PRごとにレビュー一覧も取得して、レビュー済みのレビュアーも表示する。リクエスト数がPRの数だけ増える
+    fetch_reviews: bool,
+    // レビュー依頼の代わりにAssigneeで担当を決めているチーム向けに、AssigneeもレビュアーとしてPRを割り当てる
+    use_assignees: bool,
+    // PRごとに詳細を取得して、コンフリクトしているPRに印を付ける。リクエスト数がPRの数だけ増える
+    fetch_mergeable: bool,
+    // PRごとにheadのコミットのステータスを取得して、CIの結果を表示する。リクエスト数がPRの数だけ増える
+    fetch_ci_status: bool,
+    // Organizationのメンバー一覧も取得して、担当PRのない人も行として表示する
+    show_all_members: bool,
+    // レビューの漏れを振り返れるよう、クローズ済みのPRも取得できるようにする
+    pull_request_state: PullRequestState,
+    // 最近pushされたリポジトリだけPRを取得する
+    recent_only: bool,
+    recent_days: u32,
+    load_weights: LoadWeights,
+    // 負荷順に並べるときに、件数ではなく重み付きの負荷スコアを使う
+    sort_by_weighted_load: bool,
+    repository_sort: RepositorySort,
+    // レビュアーの行を負荷順に並べる向き。Noneなら最初に見つかった順
+    load_sort: Option<SortDirection>,
+    // 1つのセルの中でのPRの並び順
+    chip_sort: ChipSort,
+    layout: Layout,
+    density: Density,
+    // (ラベル名, 色)。そのラベルが付いたPRのチップに背景色を付ける。先に書いたルールが優先される
+    label_color_rules: Vec<(String, String)>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            lang: browser_lang(),
+            refresh_secs: None,
+            sla_hours: DEFAULT_SLA_HOURS,
+            stale_days: DEFAULT_STALE_DAYS,
+            hide_self_reviews: true,
+            hide_bots: true,
+            extra_bot_logins: String::new(),
+            busy_threshold: DEFAULT_BUSY_THRESHOLD,
+            large_organization_threshold: DEFAULT_LARGE_ORGANIZATION_THRESHOLD,
+            throttle_ms: 0,
+            include_archived: false,
+            fetch_reviews: false,
+            use_assignees: false,
+            fetch_mergeable: false,
+            fetch_ci_status: false,
+            show_all_members: false,
+            pull_request_state: PullRequestState::Open,
+            recent_only: false,
+            recent_days: DEFAULT_RECENT_DAYS,
+            load_weights: LoadWeights::default(),
+            sort_by_weighted_load: false,
+            repository_sort: RepositorySort::ApiOrder,
+            load_sort: None,
+            chip_sort: ChipSort::Oldest,
+            layout: Layout::ByReviewer,
+            density: Density::Comfortable,
+            label_color_rules: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    // 保存していないか、保存内容が壊れていたら既定値にする
+    fn load() -> Self {
+        LocalStorage::get(SETTINGS_STORAGE_KEY).unwrap_or_default()
+    }
 }
 
 struct Model {
+    form: Form,
+    // 取得できたOrganizationの入力値。新しい順
+    organization_history: Vec<String>,
+    token_input: ElRef<web_sys::HtmlInputElement>,
+    // / キーでフォーカスする、レビュアーの絞り込み欄
+    reviewer_filter_field: ElRef<web_sys::HtmlInputElement>,
+    // キーボードショートカットの一覧を開いている
+    show_help: bool,
+    // 401が返ってきたため、トークンの再入力待ちになっている
+    token_expired: bool,
+    // 画面共有などで見えないよう、トークンは普段は伏せて表示する
+    show_token: bool,
+    // トークンがよくある形式ではない。送信は止めずに注意だけ出す
+    token_format_warning: bool,
+    // トークンの持ち主のlogin。自分の行を目立たせるのに使う
+    viewer_login: Option<String>,
+    // トークンの持ち主が所属するOrganization。取得できなかった場合は空にして、入力欄だけで指定してもらう
+    user_organizations: Option<Vec<String>>,
     organization: Option<Organization>,
-    error_message: Option<String>,
+    // organizationを取得した時刻（ISO 8601）
+    fetched_at: Option<String>,
+    // 取得中。再取得の間は前回のデータを表示したままにする
+    loading: bool,
+    // 取得を始めるたびに増やす。送信し直したあとに届いた前の取得の結果を見分けるのに使う
+    fetch_generation: u64,
+    // PRの取得がどこまで進んだか
+    fetch_progress: Option<FetchProgress>,
+    // リポジトリ数が多すぎるため、PR取得の確認待ちになっているリポジトリ一覧
+    pending_repositories: Option<Vec<Repository>>,
+    // リポジトリ一覧を取得できなかったOrganizationのエラー。PRの取得後に警告として表示する
+    organization_warnings: Vec<FetchError>,
+    // 確認への回答。自動更新のたびに聞き直さないよう、送信し直すまで覚えておく
+    large_fetch_choice: Option<LargeFetchChoice>,
+    // 差し替えると前のタイマーは止まるので、タイマーが重複することはない
+    refresh_stream: Option<StreamHandle>,
+    // ウォッチリストに固定したPRのid。LocalStorageに保存する
+    watched_pull_requests: Vec<u64>,
+    // 表示や取得の設定。認証情報とは別に、まとめてLocalStorageに保存する
+    settings: Settings,
+    // 表示中の表の向き。リンクで指定されたときは、保存している設定とは別にここだけ変える
+    layout: Layout,
+    // 表示中のOrganizationごとの、loginごとの同時レビュー数の上限
+    capacities: HashMap<String, HashMap<String, u32>>,
+    // 最近pushされていないため、PRを取得しなかったリポジトリの数
+    skipped_repository_count: usize,
+    // 要対応バッジで絞り込み中のリポジトリ
+    attention_repository: Option<String>,
+    // 列を1つのリポジトリ（full_name）だけに絞り込む
+    selected_repository: Option<String>,
+    // loginにこの文字列を含むレビュアーだけ表示する（大文字小文字は区別しない）
+    reviewer_filter: String,
+    // 入力欄の値。打鍵ごとに表を描き直さないよう、入力が止まってからreviewer_filterに反映する
+    reviewer_filter_input: String,
+    // 差し替えると前のタイマーは取り消される
+    reviewer_filter_timeout: Option<CmdHandle>,
+    // 担当PRがこの件数に満たないレビュアーは表示しない。0なら全員
+    min_prs: usize,
+    // 二次レート制限で止まった取得を、Retry-Afterの時刻になったらやり直すタイマー
+    auto_retry: Option<CmdHandle>,
+    // 前回のデータを表示したまま一時的なエラーが出た場合に、エラーを自動で閉じるタイマー
+    fetch_error_timeout: Option<CmdHandle>,
+    // 壁掛けモニター向けに操作部品を隠して表示だけする
+    kiosk: bool,
+    // 表示範囲の列だけ描画するために、表のスクロール位置と表示幅を覚えておく
+    board_container: ElRef<web_sys::HtmlElement>,
+    board_scroll_left: f64,
+    board_viewport_width: f64,
+    // ページ全体が縦にスクロールするので、表の上端がビューポートの上端をどれだけ過ぎたかで描画する行を決める
+    board_scrolled_past: f64,
+    board_viewport_height: f64,
+    // 描画した行の高さの平均。描画を省いた行の分の余白に使う
+    board_row_height: f64,
+    // 更新ボタンで取得し直す前のページのスクロール位置。取得し終えたら元に戻す
+    refresh_scroll: Option<(f64, f64)>,
+    fetch_error: Option<FetchError>,
+    // 接続を使い回せるよう、取得のたびに作らずに共有する。トークンはリクエストごとにヘッダーで送る
+    client: reqwest::Client,
+    response_cache: SharedResponseCache,
+    // Organizationが未入力のまま取得しようとした
+    organization_missing: bool,
+    copy_failed: bool,
+    // 直前にリンクをコピーしたPRのURL。チップに「コピーしました」を少しの間だけ出す
+    copied_link: Option<String>,
+    copied_link_timeout: Option<CmdHandle>,
+    download_failed: bool,
 }
 
 enum Msg {
+    ShortcutPressed(String),
+    SelectOrg(String),
+    UserOrganizationsFetched(Vec<String>),
+    ToggleHelp,
+    OrganizationChanged(String),
+    TeamSlugChanged(String),
+    RepoPrefixChanged(String),
+    TokenChanged(String),
+    SubmitClicked,
+    RevealTokenInput,
+    ViewerLoginFetched(Option<String>),
+    ScrollToMyRow,
+    ToggleTokenVisibility,
+    ClearCredentials,
     FetchData,
-    DataFetched(Result<Organization>),
+    // 表示中のデータやスクロール位置を残したまま取得し直す
+    Refresh,
+    // キャッシュを捨てて、すべてのリクエストを条件なしで送り直す
+    ForceRefresh,
+    RestoreScroll,
+    // 取得の結果には、どの取得のものかを示すfetch_generationを付ける
+    RepositoriesFetched(u64, Result<(Vec<Repository>, Vec<FetchError>), FetchError>),
+    ConfirmLargeFetch(LargeFetchChoice),
+    FetchProgress(u64, FetchProgress),
+    // 1つのリポジトリのPRを取得し終えた。全体の取得が終わる前に表に反映する
+    RepositoryDataFetched(u64, Repository),
+    DataFetched(u64, Result<Organization, FetchError>),
+    ToggleWatch(u64),
+    ChangeLang(Lang),
+    ChangeRefreshInterval(String),
+    ChangeSlaHours(String),
+    ChangeStaleDays(String),
+    ToggleHideSelfReviews,
+    ToggleHideBots,
+    ChangeExtraBotLogins(String),
+    ToggleIncludeArchived,
+    ToggleFetchReviews,
+    ToggleUseAssignees,
+    ToggleFetchMergeable,
+    ToggleFetchCiStatus,
+    ToggleShowAllMembers,
+    ChangePullRequestState(String),
+    ToggleRecentOnly,
+    ChangeRecentDays(String),
+    ChangeDraftWeight(String),
+    ChangeReReviewWeight(String),
+    ChangeSmallWeight(String),
+    ChangeLargeWeight(String),
+    ChangeCiFailureWeight(String),
+    SortByLoad,
+    ToggleSortByWeightedLoad,
+    ChangeLabelColorRules(String),
+    ChangeBusyThreshold(String),
+    ChangeLargeOrganizationThreshold(String),
+    ChangeThrottle(String),
+    ChangeCapacity(String, String, String),
+    ToggleAttentionFilter(String),
+    FilterReviewers(String),
+    ApplyReviewerFilter,
+    ChangeMinPrs(String),
+    SelectRepository(Option<String>),
+    ChangeRepositorySort(String),
+    ChangeChipSort(String),
+    ChangeLayout(String),
+    ToggleCompact,
+    BoardScrolled,
+    ClockTicked,
+    DismissFetchWarnings,
+    DismissError,
+    // (owner, login)
+    OpenAll(String, String),
+    CopyPlainTable,
+    CopyMarkdown,
+    ExportCsv,
+    CopyFailed,
+    CopyLink(String),
+    LinkCopied(String),
+    ClearCopiedLink,
+}
+
+// 表の列にするリポジトリの並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum RepositorySort {
+    // APIが返した順（最近pushされた順）
+    ApiOrder,
+    // 大文字小文字を区別しない名前順
+    Alphabetical,
+    // 割り当てられているPRの多い順
+    ByPullRequestCount,
+}
+
+impl RepositorySort {
+    const ALL: [RepositorySort; 3] = [
+        RepositorySort::ApiOrder,
+        RepositorySort::Alphabetical,
+        RepositorySort::ByPullRequestCount,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            RepositorySort::ApiOrder => "api",
+            RepositorySort::Alphabetical => "alphabetical",
+            RepositorySort::ByPullRequestCount => "pr_count",
+        }
+    }
+}
+
+// セルの中のPRの並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum ChipSort {
+    // 作成日時の古い順。長く待たせているPRを先に見せる
+    Oldest,
+    // PR番号順
+    Number,
+}
+
+impl ChipSort {
+    const ALL: [ChipSort; 2] = [ChipSort::Oldest, ChipSort::Number];
+
+    fn key(self) -> &'static str {
+        match self {
+            ChipSort::Oldest => "oldest",
+            ChipSort::Number => "number",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LargeFetchChoice {
+    Continue,
+    Limit(usize),
+    Cancel,
+}
+
+const PER_PAGE: usize = 100;
+// PRの取得を同時に進めるリポジトリ数
+const CONCURRENT_REPOSITORY_FETCHES: usize = 6;
+// ラベルの多いPRで行が伸びないよう、チップにはこの数までラベルを出して残りは件数だけにする
+const MAX_CHIP_LABELS: usize = 3;
+// 「すべて開く」でこれを超える数のタブを開くときは確認する
+const OPEN_ALL_CONFIRM_THRESHOLD: usize = 10;
+#[derive(Debug, Clone)]
+struct FetchProgress {
+    // PRの取得が終わったリポジトリの数
+    done: usize,
+    total: usize,
+    // 最後に取得を始めたリポジトリ
+    current_repository: String,
+}
+
+// PRのどの情報からレビュアーを集めるか
+#[derive(Debug, Clone)]
+struct FetchOptions {
+    fetch_reviews: bool,
+    use_assignees: bool,
+    hide_bots: bool,
+    extra_bot_logins: String,
+    fetch_mergeable: bool,
+    fetch_ci_status: bool,
+    show_all_members: bool,
+    pull_request_state: PullRequestState,
+    throttle_ms: u32,
+}
+
+impl FetchOptions {
+    fn from_model(model: &Model) -> Self {
+        Self {
+            fetch_reviews: model.settings.fetch_reviews,
+            use_assignees: model.settings.use_assignees,
+            hide_bots: model.settings.hide_bots,
+            extra_bot_logins: model.settings.extra_bot_logins.clone(),
+            fetch_mergeable: model.settings.fetch_mergeable,
+            fetch_ci_status: model.settings.fetch_ci_status,
+            show_all_members: model.settings.show_all_members,
+            pull_request_state: model.settings.pull_request_state,
+            throttle_ms: model.settings.throttle_ms,
+        }
+    }
+
+    // 隠すボットは集計の段階で外し、ボットにだけ依頼されたPRもレビュアーなしの行に入れる
+    fn is_hidden_bot(&self, login: &str) -> bool {
+        self.hide_bots && is_bot(login, &self.extra_bot_logins)
+    }
+}
+
+// headのコミットに付いたステータスをまとめた結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum CiStatus {
+    Success,
+    Pending,
+    Failure,
+}
+
+impl CiStatus {
+    // /commits/{sha}/status のレスポンスから読み取る。ステータスが1つもなければCIなしとみなす
+    fn from_combined_status(status: &serde_json::Value) -> Option<CiStatus> {
+        if status["total_count"].as_u64().unwrap_or_default() == 0 {
+            return None;
+        }
+        match status["state"].as_str()? {
+            "success" => Some(CiStatus::Success),
+            "pending" => Some(CiStatus::Pending),
+            "failure" | "error" => Some(CiStatus::Failure),
+            _ => None,
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            CiStatus::Success => "#28a745",
+            CiStatus::Pending => "#dbab09",
+            CiStatus::Failure => "#d73a49",
+        }
+    }
+}
+
+// 表の向き。ByRepositoryでは行と列を入れ替えて、リポジトリを行にする
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Layout {
+    ByReviewer,
+    ByRepository,
+}
+
+impl Layout {
+    const ALL: [Layout; 2] = [Layout::ByReviewer, Layout::ByRepository];
+
+    fn key(self) -> &'static str {
+        match self {
+            Layout::ByReviewer => "by_reviewer",
+            Layout::ByRepository => "by_repository",
+        }
+    }
+}
+
+// 表の詰め具合。Compactでは文字と余白を小さくして、大きなOrganizationでも一度に多くの行を見られるようにする
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Density {
+    Comfortable,
+    Compact,
+}
+
+// pulls APIのstateパラメーター
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum PullRequestState {
+    Open,
+    Closed,
+    All,
+}
+
+impl PullRequestState {
+    const ALL: [PullRequestState; 3] = [
+        PullRequestState::Open,
+        PullRequestState::Closed,
+        PullRequestState::All,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            PullRequestState::Open => "open",
+            PullRequestState::Closed => "closed",
+            PullRequestState::All => "all",
+        }
+    }
+}
+
+// 取得中の非同期処理とも共有する、レスポンスから得た情報
+type SharedResponseCache = Rc<RefCell<ResponseCache>>;
+
+#[derive(Default)]
+struct ResponseCache {
+    // URLごとの、最後に取得したページのETagと内容
+    pages: HashMap<String, CachedPage>,
+    rate_limit: Option<RateLimitBudget>,
+}
+
+impl ResponseCache {
+    // 同時に取得しているとレスポンスの順番が前後するので、同じ時間枠の中では残りの少ないほうを残す
+    fn record_rate_limit(&mut self, budget: RateLimitBudget) {
+        let is_newer = self.rate_limit.is_none_or(|current| {
+            budget.reset > current.reset || budget.remaining < current.remaining
+        });
+        if is_newer {
+            self.rate_limit = Some(budget);
+        }
+    }
+}
+
+// GitHub APIのレート制限の残り。resetはUNIX時刻（秒）
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RateLimitBudget {
+    remaining: u32,
+    limit: u32,
+    reset: f64,
+}
+
+struct CachedPage {
+    etag: String,
+    // 検証済みのJSON（一覧系APIなら配列、詳細ならオブジェクト）
+    items: serde_json::Value,
+    next_url: Option<String>,
+}
+
+// loginが"[bot]"で終わらないが、レビュアーとして割り当てられることのあるボット
+const KNOWN_BOT_LOGINS: [&str; 4] = ["dependabot", "renovate", "github-actions", "codecov"];
+
+fn is_bot(login: &str, extra_bot_logins: &str) -> bool {
+    let login = login.to_lowercase();
+    login.ends_with("[bot]")
+        || KNOWN_BOT_LOGINS.contains(&login.as_str())
+        || extra_bot_logins
+            .split(',')
+            .map(str::trim)
+            .any(|bot| !bot.is_empty() && bot.eq_ignore_ascii_case(&login))
 }
 
-fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
-    Model {
+// レビュアーが誰もいないPRをまとめる行。GitHubのloginには括弧を使えないので、実在のユーザーとは重ならない
+const UNASSIGNED_REVIEWER: &str = "(unassigned)";
+// GitHubが応答しないまま取得中の表示が続かないよう、1回のリクエストをこの時間（ミリ秒）で打ち切る
+const REQUEST_TIMEOUT_MS: u32 = 15_000;
+// 接続エラーや5xxのときに、この間隔（ミリ秒）を空けて再試行する
+const RETRY_DELAYS_MS: [u32; 3] = [250, 500, 1000];
+const DEFAULT_LARGE_ORGANIZATION_THRESHOLD: usize = 150;
+const DEFAULT_STALE_DAYS: u32 = 7;
+// 負荷スコアでPRのサイズを分ける、追加と削除の行数の合計
+const SMALL_PULL_REQUEST_MAX_LINES: u64 = 100;
+const LARGE_PULL_REQUEST_MIN_LINES: u64 = 500;
+const MILLIS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+const MILLIS_PER_HOUR: f64 = 60.0 * 60.0 * 1000.0;
+const DEFAULT_SLA_HOURS: u32 = 24;
+const DEFAULT_RECENT_DAYS: u32 = 30;
+const DEFAULT_BUSY_THRESHOLD: u32 = 5;
+// 自動更新の間隔の選択肢（秒）
+const REFRESH_INTERVAL_OPTIONS: [u32; 3] = [30, 60, 5 * 60];
+// キオスクモードでは自動更新を切れないので、未設定ならこの間隔で更新する
+const KIOSK_REFRESH_SECS: u32 = 5 * 60;
+// 最終更新からの経過時間（分単位）の表示を描画し直す間隔（ミリ秒）
+const CLOCK_TICK_MS: u32 = 30_000;
+// レビュアーの絞り込みは最後の入力からこの時間（ミリ秒）が経ってから反映する
+const REVIEWER_FILTER_DEBOUNCE_MS: u32 = 200;
+// 一時的なエラーの通知を自動で閉じるまでの時間（ミリ秒）
+const FETCH_ERROR_DISMISS_MS: u32 = 10_000;
+// チップに「コピーしました」を出しておく時間（ミリ秒）
+const COPIED_LINK_DISPLAY_MS: u32 = 2_000;
+// 表示範囲を計算できるように列幅は固定にする
+const BOARD_REVIEWER_COLUMN_WIDTH_PX: f64 = 160.0;
+const BOARD_TOTAL_COLUMN_WIDTH_PX: f64 = 110.0;
+// 横スクロールしても固定表示する、レビュアー列と合計列の幅
+const BOARD_FIXED_COLUMNS_WIDTH_PX: f64 =
+    BOARD_REVIEWER_COLUMN_WIDTH_PX + BOARD_TOTAL_COLUMN_WIDTH_PX;
+const BOARD_COLUMN_WIDTH_PX: f64 = 240.0;
+// スクロールしてすぐ見える位置の列は、画面外でも描画しておく
+const BOARD_COLUMN_OVERSCAN: usize = 2;
+// 行は高さがPRの数で変わるので、これより少なければ間引かずに全部描画する
+const BOARD_VIRTUALIZED_MIN_ROWS: usize = 100;
+// まだ1行も描画していないときに使う行の高さ
+const BOARD_ROW_HEIGHT_ESTIMATE_PX: f64 = 40.0;
+// 行の高さは推定なので、列よりも多めに画面外の行を描画しておく
+const BOARD_ROW_OVERSCAN: usize = 10;
+
+fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    let kiosk = url
+        .search()
+        .get("kiosk")
+        .is_some_and(|values| values.iter().any(|value| value == "1"));
+    // キオスクモードでは操作できないので、起動時に取得して以降は定期的に再取得する
+    if kiosk {
+        orders.send_msg(Msg::FetchData);
+    }
+    orders.stream(streams::window_event(Ev::Resize, |_| Msg::BoardScrolled));
+    orders.stream(streams::window_event(Ev::Scroll, |_| Msg::BoardScrolled));
+    // キオスクモードには操作するものがないので、ショートカットも受け付けない
+    if !kiosk {
+        orders.stream(streams::window_event(Ev::KeyDown, |event| {
+            shortcut_key(event.unchecked_into()).map(Msg::ShortcutPressed)
+        }));
+    }
+    // 開いたままにしていても「n分前に更新」の表示が古くならないよう、定期的に描画し直す
+    orders.stream(streams::interval(CLOCK_TICK_MS, || Msg::ClockTicked));
+    // 共有されたリンクの表示状態。トークンはURLに載せず、LocalStorageのものを使う
+    let url_param = |key: &str| {
+        url.search()
+            .get(key)
+            .and_then(|values| values.first())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let url_organization = url_param(URL_ORGANIZATION_KEY);
+    let url_repository = url_param(URL_REPOSITORY_KEY);
+    let url_reviewer_filter = url_param(URL_REVIEWER_FILTER_KEY);
+    let url_layout = url_param(URL_LAYOUT_KEY).and_then(|key| {
+        Layout::ALL
+            .iter()
+            .copied()
+            .find(|layout| layout.key() == key)
+    });
+    let mut form = Form {
+        organization: LocalStorage::get(ORGANIZATION_STORAGE_KEY).unwrap_or_default(),
+        token: LocalStorage::get(TOKEN_STORAGE_KEY).unwrap_or_default(),
+        team_slug: LocalStorage::get(TEAM_SLUG_STORAGE_KEY).unwrap_or_default(),
+        repo_prefix: LocalStorage::get(REPO_PREFIX_STORAGE_KEY).unwrap_or_default(),
+    };
+    let opened_other_organization = url_organization.is_some();
+    if let Some(organization) = url_organization {
+        form.organization = organization;
+    }
+    // 入力欄と同じOrganizationのデータだけ復元し、裏で最新のデータを取得し直す
+    let last_fetch = LocalStorage::get::<_, LastFetch>(LAST_FETCH_STORAGE_KEY)
+        .ok()
+        .filter(|last_fetch| {
+            last_fetch
+                .organization
+                .names
+                .iter()
+                .map(|name| name.to_lowercase())
+                .eq(form.organization_names().into_iter().map(str::to_lowercase))
+        });
+    // リンクで別のOrganizationを開いた場合も、トークンが保存されていればすぐに取得する
+    if (last_fetch.is_some() || opened_other_organization && !form.token.is_empty()) && !kiosk {
+        orders.send_msg(Msg::FetchData);
+    }
+    let settings = Settings::load();
+    let layout = url_layout.unwrap_or(settings.layout);
+    let mut model = Model {
+        form,
+        organization_history: LocalStorage::get(ORGANIZATION_HISTORY_STORAGE_KEY)
+            .unwrap_or_default(),
+        token_input: ElRef::new(),
+        reviewer_filter_field: ElRef::new(),
+        show_help: false,
+        token_expired: false,
+        show_token: false,
+        token_format_warning: false,
+        viewer_login: None,
+        user_organizations: None,
         organization: None,
-        error_message: None,
+        fetched_at: None,
+        loading: false,
+        fetch_generation: 0,
+        fetch_progress: None,
+        pending_repositories: None,
+        organization_warnings: Vec::new(),
+        large_fetch_choice: None,
+        refresh_stream: None,
+        settings,
+        layout,
+        watched_pull_requests: LocalStorage::get(WATCHED_PULL_REQUESTS_STORAGE_KEY)
+            .unwrap_or_default(),
+        skipped_repository_count: 0,
+        capacities: HashMap::new(),
+        attention_repository: None,
+        selected_repository: None,
+        reviewer_filter: String::new(),
+        reviewer_filter_input: String::new(),
+        min_prs: 0,
+        reviewer_filter_timeout: None,
+        auto_retry: None,
+        fetch_error_timeout: None,
+        kiosk,
+        board_container: ElRef::new(),
+        board_scroll_left: 0.0,
+        refresh_scroll: None,
+        board_viewport_width: window()
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default(),
+        board_scrolled_past: 0.0,
+        board_viewport_height: window_inner_height(),
+        board_row_height: BOARD_ROW_HEIGHT_ESTIMATE_PX,
+        fetch_error: None,
+        organization_missing: false,
+        client: reqwest::Client::new(),
+        response_cache: SharedResponseCache::default(),
+        copy_failed: false,
+        copied_link: None,
+        copied_link_timeout: None,
+        download_failed: false,
+    };
+    if let Some(last_fetch) = last_fetch {
+        set_organization(&mut model, last_fetch.organization, last_fetch.fetched_at);
+    }
+    model.selected_repository = url_repository;
+    if let Some(reviewer_filter) = url_reviewer_filter {
+        model.reviewer_filter_input = reviewer_filter.clone();
+        model.reviewer_filter = reviewer_filter;
     }
+    schedule_refresh(&mut model, orders);
+    model
+}
+
+fn schedule_refresh(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let refresh_secs = if model.kiosk {
+        model.settings.refresh_secs.or(Some(KIOSK_REFRESH_SECS))
+    } else {
+        model.settings.refresh_secs
+    };
+    model.refresh_stream = refresh_secs.map(|refresh_secs| {
+        orders.stream_with_handle(streams::interval(refresh_secs * 1000, || Msg::FetchData))
+    });
+}
+
+// ブックマークや共有のため、表示中のOrganizationと絞り込み・表の向きをURLのクエリに反映する。
+// 履歴を増やさないようにreplaceする
+fn sync_url(model: &Model) {
+    let current = Url::current();
+    let mut url = current.clone();
+    let params = [
+        (
+            URL_ORGANIZATION_KEY,
+            model
+                .organization
+                .as_ref()
+                .map(|organization| organization.names.join(","))
+                .unwrap_or_default(),
+        ),
+        (
+            URL_REPOSITORY_KEY,
+            model.selected_repository.clone().unwrap_or_default(),
+        ),
+        (
+            URL_REVIEWER_FILTER_KEY,
+            model.reviewer_filter.trim().to_string(),
+        ),
+        (
+            URL_LAYOUT_KEY,
+            if model.organization.is_some() {
+                model.layout.key().to_string()
+            } else {
+                String::new()
+            },
+        ),
+    ];
+    let search = url.search_mut();
+    for (key, value) in params {
+        if value.is_empty() {
+            search.remove(key);
+        } else {
+            search.insert(key.to_string(), vec![value]);
+        }
+    }
+    if url != current {
+        url.go_and_replace();
+    }
+}
+
+// 言語が未選択の場合はブラウザの言語設定に合わせる
+fn browser_lang() -> Lang {
+    window()
+        .navigator()
+        .language()
+        .and_then(|code| Lang::from_code(&code))
+        .unwrap_or_default()
 }
 
 #[wasm_bindgen(start)]
@@ -47,161 +1143,3604 @@ pub async fn start() {
 
 fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
     match msg {
-        Msg::FetchData => {
+        Msg::OrganizationChanged(organization) => model.form.organization = organization,
+        Msg::TeamSlugChanged(team_slug) => model.form.team_slug = team_slug,
+        Msg::RepoPrefixChanged(repo_prefix) => model.form.repo_prefix = repo_prefix,
+        Msg::TokenChanged(token) => model.form.token = token,
+        Msg::SubmitClicked => {
+            model.form.organization = model
+                .form
+                .organization
+                .split(',')
+                .map(normalize_organization)
+                .filter(|name| !name.is_empty())
+                .collect::<Vec<_>>()
+                .join(", ");
+            model.form.token = model.form.token.trim().to_string();
+            model.token_format_warning =
+                !model.form.token.is_empty() && !has_known_token_prefix(&model.form.token);
+            model.form.team_slug = model.form.team_slug.trim().to_string();
+            model.form.repo_prefix = model.form.repo_prefix.trim().to_string();
+            // 空のままだと /orgs//repos を叩いて分かりにくい404になるので、リクエストの前に止める
+            model.organization_missing = model.form.organization.is_empty();
+            if model.organization_missing {
+                return;
+            }
+            LocalStorage::insert(ORGANIZATION_STORAGE_KEY, &model.form.organization).ok();
+            // 所属するOrganizationの一覧はトークンごとに違うので、トークンを替えたときだけ取り直す
+            let stored_token: Option<String> = LocalStorage::get(TOKEN_STORAGE_KEY).ok();
+            if stored_token.as_ref() != Some(&model.form.token) {
+                model.user_organizations = None;
+            }
+            LocalStorage::insert(TOKEN_STORAGE_KEY, &model.form.token).ok();
+            LocalStorage::insert(TEAM_SLUG_STORAGE_KEY, &model.form.team_slug).ok();
+            LocalStorage::insert(REPO_PREFIX_STORAGE_KEY, &model.form.repo_prefix).ok();
+            // トークン切れで止まっていた取得も、新しいトークンでここからやり直す
+            model.token_expired = false;
+            model.viewer_login = None;
+            // 別のOrganizationを指定したかもしれないので、前回のデータや回答は持ち越さない
             model.organization = None;
-            model.error_message = None;
-            // TODO: perform_cmdはasync fnを引数に取れるのでコメントしたような渡し方は不要のはず。確認して削除する
-            // let future = async { fetch_organization_data().map(Msg::DataFetched).await };
-            let future = fetch_organization_data().map(Msg::DataFetched);
-            orders.perform_cmd(future);
+            model.fetched_at = None;
+            model.large_fetch_choice = None;
+            model.loading = false;
+            orders.send_msg(Msg::FetchData);
         }
-        Msg::DataFetched(result) => match result {
-            Ok(organization) => model.organization = Some(organization),
-            Err(err) => model.error_message = Some(err.to_string()),
+        Msg::ShortcutPressed(key) => match key.as_str() {
+            "r" if model.organization.is_some() => {
+                orders.send_msg(Msg::Refresh);
+            }
+            "/" => {
+                if let Some(field) = model.reviewer_filter_field.get() {
+                    field.focus().ok();
+                }
+            }
+            "?" => model.show_help = !model.show_help,
+            "Escape" if model.show_help => model.show_help = false,
+            _ => {
+                orders.skip();
+            }
         },
-    }
-}
-
-async fn fetch_organization_data() -> Result<Organization> {
-    let organization_name = "my-organization";
-    let access_token = "my-access-token";
-    let mut headers = HeaderMap::new();
-    headers.insert(
-        header::AUTHORIZATION,
-        format!("Bearer {}", access_token).parse().unwrap(),
-    );
-    headers.insert(header::USER_AGENT, "my-app".parse().unwrap());
-    // セッションを再利用して複数回リクエストするためのインスタンスを生成する
-    let client = reqwest::Client::new();
-    let repositories_url = format!("https://api.github.com/orgs/{}/repos", organization_name);
-    let repositories_response = &client
-        .get(&repositories_url)
-        .headers(headers.clone())
-        .send()
-        .await
-        .with_context(|| format!("Failed to fetch repositories from {}", repositories_url))?
-        .text()
-        .await
-        .with_context(|| "Failed to parse repositories response")?;
-    let mut repositories: Vec<Repository> =
-        serde_json::from_str(&repositories_response).unwrap_or_else(|_| Vec::new());
-    for repository in &mut repositories {
-        let pulls_url = format!(
-            "https://api.github.com/repos/{}/{}/pulls?state=open",
-            organization_name, repository.name
-        );
-        let pulls_response = &client
-            .get(&pulls_url)
-            .headers(headers.clone())
-            .send()
-            .await
-            .with_context(|| format!("Failed to fetch pull requests from {}", pulls_url))?
-            .text()
-            .await
-            .with_context(|| "Failed to parse pull requests response")?;
-        let pulls: Vec<serde_json::Value> = serde_json::from_str(&pulls_response)
-            .with_context(|| "Failed to parse pull requests")?;
-        for pull in pulls {
-            // TODO: Assignee不要なら消す
-            // let empty_vec = Vec::new();
-            // let assignees = match pull["assignees"].as_array() {
-            //     Some(assignees) => assignees,
-            //     None => &empty_vec,
-            // };
-            // let assignee_logins: Vec<String> = assignees
-            //     .iter()
-            //     .map(|a| a["login"].as_str().unwrap().to_string())
-            //     .collect();
-            let reviews_url = pull["url"]
-                .as_str()
-                .unwrap()
-                .replace("api.", "")
-                .replace("/pulls/", "/pulls/")
-                + "/reviews";
-            let reviews_response = &client
-                .get(&reviews_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .with_context(|| format!("Failed to fetch reviews from {}", reviews_url))?
-                .text()
-                .await
-                .with_context(|| "Failed to parse reviews response")?;
-            let reviews: Vec<serde_json::Value> = serde_json::from_str(&reviews_response)
-                .with_context(|| "Failed to parse reviews")?;
-            for review in reviews {
-                let reviewer_login = review["user"]["login"].as_str().unwrap().to_string();
-                let state = review["state"].as_str().unwrap().to_string();
-                if state != "COMMENTED" && state != "DISMISSED" {
-                    if let Some(reviewer) = repository
-                        .reviewers
-                        .iter_mut()
-                        .find(|r| r.name == reviewer_login)
-                    {
-                        reviewer
-                            .assigned_pull_requests
-                            .push(pull["url"].as_str().unwrap().to_string());
+        Msg::ToggleHelp => model.show_help = !model.show_help,
+        Msg::ViewerLoginFetched(login) => model.viewer_login = login,
+        Msg::UserOrganizationsFetched(organizations) => {
+            model.user_organizations = Some(organizations);
+        }
+        Msg::SelectOrg(organization) => {
+            if organization.is_empty() {
+                return;
+            }
+            model.form.organization = organization;
+            orders.send_msg(Msg::SubmitClicked);
+        }
+        Msg::ScrollToMyRow => {
+            if let Ok(Some(row)) = document().query_selector(".board .me") {
+                row.scroll_into_view();
+                return;
+            }
+            // 画面外の行は描画していないので、自分の行が描画される位置まで表示範囲を動かしてからスクロールする
+            let index = model.organization.as_ref().and_then(|organization| {
+                let repositories = board_repositories(organization, model);
+                board_rows(organization, &repositories, model)
+                    .iter()
+                    .position(|row| model.viewer_login.as_deref() == Some(row.login))
+            });
+            if let Some(index) = index {
+                model.board_scrolled_past = index as f64 * model.board_row_height;
+                orders.after_next_render(|_| Msg::ScrollToMyRow);
+            }
+        }
+        Msg::RevealTokenInput => {
+            if let Some(token_input) = model.token_input.get() {
+                token_input.scroll_into_view();
+                token_input.focus().ok();
+            }
+        }
+        Msg::ToggleTokenVisibility => model.show_token = !model.show_token,
+        // 共有端末で使ったあとに、保存したトークンを残さないようにする
+        Msg::ClearCredentials => {
+            LocalStorage::remove(ORGANIZATION_STORAGE_KEY).ok();
+            LocalStorage::remove(TOKEN_STORAGE_KEY).ok();
+            LocalStorage::remove(TEAM_SLUG_STORAGE_KEY).ok();
+            LocalStorage::remove(REPO_PREFIX_STORAGE_KEY).ok();
+            LocalStorage::remove(ORGANIZATION_HISTORY_STORAGE_KEY).ok();
+            model.organization_history.clear();
+            LocalStorage::remove(LAST_FETCH_STORAGE_KEY).ok();
+            model.response_cache.borrow_mut().pages.clear();
+            model.form = Form::default();
+            model.token_expired = false;
+            model.token_format_warning = false;
+            model.viewer_login = None;
+            model.user_organizations = None;
+            model.organization = None;
+            model.fetched_at = None;
+            model.pending_repositories = None;
+            model.large_fetch_choice = None;
+            model.fetch_error = None;
+            model.fetch_error_timeout = None;
+            model.auto_retry = None;
+            model.organization_missing = false;
+            sync_url(model);
+        }
+        Msg::FetchData => {
+            // 自動更新が前回の取得に追いついた場合は重ねて取得しない
+            if model.loading {
+                return;
+            }
+            model.organization_missing = model.form.organization_names().is_empty();
+            if model.organization_missing {
+                return;
+            }
+            model.loading = true;
+            model.fetch_generation += 1;
+            let generation = model.fetch_generation;
+            model.pending_repositories = None;
+            model.skipped_repository_count = 0;
+            model.fetch_error = None;
+            model.fetch_error_timeout = None;
+            // 手動で再試行した場合は、予約していた自動の再試行を取り消す
+            model.auto_retry = None;
+            let future = fetch_repositories(
+                model.client.clone(),
+                model.form.clone(),
+                model.response_cache.clone(),
+            )
+            .map(move |result| Msg::RepositoriesFetched(generation, result));
+            orders.perform_cmd(future);
+            if model.viewer_login.is_none() {
+                orders.perform_cmd(
+                    fetch_viewer_login(
+                        model.client.clone(),
+                        model.form.token.clone(),
+                        model.response_cache.clone(),
+                    )
+                    .map(Msg::ViewerLoginFetched),
+                );
+            }
+            if model.user_organizations.is_none() {
+                orders.perform_cmd(
+                    fetch_user_organizations(
+                        model.client.clone(),
+                        model.form.token.clone(),
+                        model.response_cache.clone(),
+                    )
+                    .map(Msg::UserOrganizationsFetched),
+                );
+            }
+        }
+        // 表示中のデータ・絞り込み・並び順はそのままにして、裏で取得し直す
+        Msg::Refresh => {
+            if model.loading {
+                return;
+            }
+            let window = window();
+            model.refresh_scroll = window.scroll_x().ok().zip(window.scroll_y().ok());
+            orders.send_msg(Msg::FetchData);
+        }
+        Msg::RestoreScroll => {
+            if let Some((x, y)) = model.refresh_scroll.take() {
+                window().scroll_to_with_x_and_y(x, y);
+            }
+        }
+        Msg::ForceRefresh => {
+            if model.loading {
+                return;
+            }
+            model.response_cache.borrow_mut().pages.clear();
+            orders.send_msg(Msg::FetchData);
+        }
+        // 送信し直す前に始めた取得の結果は、新しい表に混ざらないよう捨てる
+        Msg::RepositoriesFetched(generation, _)
+        | Msg::FetchProgress(generation, _)
+        | Msg::RepositoryDataFetched(generation, _)
+        | Msg::DataFetched(generation, _)
+            if generation != model.fetch_generation => {}
+        Msg::RepositoriesFetched(_, result) => match result {
+            Ok((mut repositories, organization_warnings)) => {
+                model.organization_warnings = organization_warnings;
+                if !model.settings.include_archived {
+                    repositories.retain(|repository| !repository.archived);
+                }
+                // GitHubの一覧APIには名前で絞り込む指定がないので、取得した一覧から外す
+                if !model.form.repo_prefix.is_empty() {
+                    let repo_prefix = &model.form.repo_prefix;
+                    repositories.retain(|repository| {
+                        strip_prefix_ignore_ascii_case(&repository.name, repo_prefix).is_some()
+                    });
+                }
+                if model.settings.recent_only {
+                    let repository_count = repositories.len();
+                    repositories.retain(|repository| {
+                        pushed_recently(repository, model.settings.recent_days)
+                    });
+                    model.skipped_repository_count = repository_count - repositories.len();
+                }
+                if repositories.len() > model.settings.large_organization_threshold {
+                    model.pending_repositories = Some(repositories);
+                    // キオスクモードでは確認に答えられないので、件数を制限して続ける
+                    let choice = if model.kiosk {
+                        Some(LargeFetchChoice::Limit(
+                            model.settings.large_organization_threshold,
+                        ))
                     } else {
-                        repository.reviewers.push(Reviewer {
-                            name: reviewer_login.clone(),
-                            assigned_pull_requests: vec![pull["url"].as_str().unwrap().to_string()],
-                        });
+                        model.large_fetch_choice
+                    };
+                    match choice {
+                        Some(choice) => {
+                            orders.send_msg(Msg::ConfirmLargeFetch(choice));
+                        }
+                        None => model.loading = false,
+                    }
+                } else {
+                    fetch_pull_requests(repositories, model, orders);
+                }
+            }
+            Err(err) => handle_fetch_error(err, model, orders),
+        },
+        Msg::ConfirmLargeFetch(choice) => {
+            if let Some(mut repositories) = model.pending_repositories.take() {
+                match choice {
+                    LargeFetchChoice::Continue => {}
+                    LargeFetchChoice::Limit(limit) => repositories.truncate(limit),
+                    LargeFetchChoice::Cancel => {
+                        model.loading = false;
+                        model.refresh_scroll = None;
+                        return;
                     }
                 }
+                model.large_fetch_choice = Some(choice);
+                model.loading = true;
+                fetch_pull_requests(repositories, model, orders);
             }
         }
-    }
-
-    Ok(Organization {
-        name: organization_name.to_string(),
-        repositories,
-    })
-}
-
-fn view(model: &Model) -> Node<Msg> {
-    div![
-        h1!("GitHub Organization Reviewers"),
-        button!["Fetch data", ev(Ev::Click, |_| Msg::FetchData),],
-        match &model.organization {
-            Some(organization) => {
-                div![
-                    p![format!("Organization: {}", organization.name)],
-                    div![
-                        C!["repositories"],
-                        organization.repositories.iter().map(|repository| {
-                            div![
-                                h2![&repository.name],
-                                div![
-                                    C!["reviewers"],
-                                    repository.reviewers.iter().map(|reviewer| {
-                                        div![
-                                            C!["reviewer"],
-                                            p![&reviewer.name],
-                                            div![
-                                                C!["pull-requests"],
-                                                reviewer.assigned_pull_requests.iter().map(|url| {
-                                                    a![
-                                                        attrs! {
-                                                        At::Href => url,
-                                                        },
-                                                        &url
-                                                    ]
-                                                }),
-                                            ],
-                                        ]
-                                    }),
-                                ],
-                            ]
-                        }),
-                    ],
-                ]
+        Msg::FetchProgress(_, progress) => model.fetch_progress = Some(progress),
+        Msg::RepositoryDataFetched(_, repository) => {
+            let form = &model.form;
+            let organization = model.organization.get_or_insert_with(|| Organization {
+                names: form
+                    .organization_names()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                repositories: Vec::new(),
+                members: Vec::new(),
+                warnings: Vec::new(),
+            });
+            let existing = organization
+                .repositories
+                .iter_mut()
+                .find(|existing| existing.full_name == repository.full_name);
+            // ウォッチリストからは、取得できたリポジトリでクローズされたPRを外す。
+            // オープンなPRはすべて取得しているので、前回あったのに見つからなくなったPRもマージかクローズされたものとみなす。
+            // 取得に失敗したリポジトリや取得対象から外れたリポジトリのPRはここに来ないので残る
+            let lists_all_open = model.settings.pull_request_state != PullRequestState::Closed;
+            let watched_count = model.watched_pull_requests.len();
+            model.watched_pull_requests.retain(|&id| {
+                match repository
+                    .pull_requests
+                    .iter()
+                    .find(|pull_request| pull_request.id == id)
+                {
+                    Some(pull_request) => !pull_request.closed,
+                    None => {
+                        !lists_all_open
+                            || existing.as_ref().is_none_or(|existing| {
+                                existing
+                                    .pull_requests
+                                    .iter()
+                                    .all(|pull_request| pull_request.id != id)
+                            })
+                    }
+                }
+            });
+            if model.watched_pull_requests.len() != watched_count {
+                save_watched_pull_requests(&model.watched_pull_requests);
             }
-            None => {
-                match &model.error_message {
-                    Some(error_message) => p![error_message],
-                    None => p!["Click the button to fetch data."],
+            // 再取得中は前回のデータを取得できたリポジトリから順に置き換える。
+            // 並び順や警告は、全体の取得が終わったときにDataFetchedでまとめて揃える
+            match existing {
+                Some(existing) => *existing = repository,
+                None => organization.repositories.push(repository),
+            }
+        }
+        Msg::DataFetched(_, result) => match result {
+            Ok(mut organization) => {
+                model.loading = false;
+                model.fetch_progress = None;
+                // 絞り込んでいたリポジトリがなくなったら、空の表にならないよう絞り込みをやめる
+                if let Some(full_name) = &model.selected_repository {
+                    if !organization
+                        .repositories
+                        .iter()
+                        .any(|repository| &repository.full_name == full_name)
+                    {
+                        model.selected_repository = None;
+                    }
                 }
+                organization
+                    .warnings
+                    .splice(0..0, model.organization_warnings.drain(..));
+                let last_fetch = LastFetch {
+                    fetched_at: js_sys::Date::new_0().to_iso_string().into(),
+                    organization,
+                };
+                // 大きなOrganizationでは容量を超えることがあるが、保存できなくても表示には困らない
+                LocalStorage::insert(LAST_FETCH_STORAGE_KEY, &last_fetch).ok();
+                remember_organization(model);
+                set_organization(model, last_fetch.organization, last_fetch.fetched_at);
+                sync_url(model);
+                if model.refresh_scroll.is_some() {
+                    orders.after_next_render(|_| Msg::RestoreScroll);
+                }
+            }
+            Err(err) => handle_fetch_error(err, model, orders),
+        },
+        Msg::ToggleWatch(id) => {
+            if let Some(index) = model.watched_pull_requests.iter().position(|&w| w == id) {
+                model.watched_pull_requests.remove(index);
+            } else {
+                model.watched_pull_requests.push(id);
             }
+            save_watched_pull_requests(&model.watched_pull_requests);
+        }
+        Msg::ChangeLang(lang) => {
+            model.settings.lang = lang;
+            save_settings(&model.settings);
         }
+        Msg::ChangeRefreshInterval(value) => {
+            model.settings.refresh_secs = value.parse().ok();
+            save_settings(&model.settings);
+            schedule_refresh(model, orders);
+        }
+        Msg::ChangeSlaHours(value) => {
+            if let Ok(sla_hours @ 1..) = value.trim().parse::<u32>() {
+                model.settings.sla_hours = sla_hours;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeStaleDays(value) => {
+            if let Ok(stale_days @ 1..) = value.trim().parse::<u32>() {
+                model.settings.stale_days = stale_days;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ToggleHideSelfReviews => {
+            model.settings.hide_self_reviews = !model.settings.hide_self_reviews;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleHideBots => {
+            model.settings.hide_bots = !model.settings.hide_bots;
+            save_settings(&model.settings);
+        }
+        Msg::ChangeExtraBotLogins(value) => {
+            model.settings.extra_bot_logins = value;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleIncludeArchived => {
+            model.settings.include_archived = !model.settings.include_archived;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleFetchReviews => {
+            model.settings.fetch_reviews = !model.settings.fetch_reviews;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleFetchMergeable => {
+            model.settings.fetch_mergeable = !model.settings.fetch_mergeable;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleFetchCiStatus => {
+            model.settings.fetch_ci_status = !model.settings.fetch_ci_status;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleShowAllMembers => {
+            model.settings.show_all_members = !model.settings.show_all_members;
+            save_settings(&model.settings);
+        }
+        Msg::ToggleUseAssignees => {
+            model.settings.use_assignees = !model.settings.use_assignees;
+            save_settings(&model.settings);
+        }
+        Msg::ChangePullRequestState(key) => {
+            if let Some(&pull_request_state) = PullRequestState::ALL
+                .iter()
+                .find(|pull_request_state| pull_request_state.key() == key)
+            {
+                model.settings.pull_request_state = pull_request_state;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ToggleRecentOnly => {
+            model.settings.recent_only = !model.settings.recent_only;
+            save_settings(&model.settings);
+        }
+        Msg::ChangeRecentDays(value) => {
+            if let Ok(recent_days @ 1..) = value.trim().parse::<u32>() {
+                model.settings.recent_days = recent_days;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeDraftWeight(value) => {
+            if let Some(weight) = parse_load_weight(&value) {
+                model.settings.load_weights.draft = weight;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeReReviewWeight(value) => {
+            if let Some(weight) = parse_load_weight(&value) {
+                model.settings.load_weights.re_review = weight;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeSmallWeight(value) => {
+            if let Some(weight) = parse_load_weight(&value) {
+                model.settings.load_weights.small = weight;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeLargeWeight(value) => {
+            if let Some(weight) = parse_load_weight(&value) {
+                model.settings.load_weights.large = weight;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeCiFailureWeight(value) => {
+            if let Some(weight) = parse_load_weight(&value) {
+                model.settings.load_weights.ci_failure = weight;
+                save_settings(&model.settings);
+            }
+        }
+        // 最初は多い順にし、以降はクリックのたびに向きを入れ替える
+        Msg::SortByLoad => {
+            model.settings.load_sort = match model.settings.load_sort {
+                Some(SortDirection::Descending) => Some(SortDirection::Ascending),
+                Some(SortDirection::Ascending) | None => Some(SortDirection::Descending),
+            };
+            save_settings(&model.settings);
+        }
+        Msg::ToggleSortByWeightedLoad => {
+            model.settings.sort_by_weighted_load = !model.settings.sort_by_weighted_load;
+            save_settings(&model.settings);
+        }
+        Msg::ChangeLabelColorRules(value) => {
+            model.settings.label_color_rules = parse_label_color_rules(&value);
+            save_settings(&model.settings);
+        }
+        Msg::ChangeLargeOrganizationThreshold(value) => {
+            if let Ok(threshold @ 1..) = value.trim().parse::<usize>() {
+                model.settings.large_organization_threshold = threshold;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeThrottle(value) => {
+            if let Ok(throttle_ms) = value.trim().parse::<u32>() {
+                model.settings.throttle_ms = throttle_ms;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeBusyThreshold(value) => {
+            if let Ok(busy_threshold) = value.trim().parse::<u32>() {
+                model.settings.busy_threshold = busy_threshold;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeCapacity(owner, login, value) => {
+            let capacities = model.capacities.entry(owner.clone()).or_default();
+            // 空欄にした場合は上限なしに戻す
+            match value.trim().parse::<u32>() {
+                Ok(capacity) => {
+                    capacities.insert(login, capacity);
+                }
+                Err(_) if value.trim().is_empty() => {
+                    capacities.remove(&login);
+                }
+                Err(_) => return,
+            }
+            LocalStorage::insert(capacities_storage_key(&owner), capacities).ok();
+        }
+        Msg::FilterReviewers(filter) => {
+            model.reviewer_filter_input = filter;
+            // 空にしたときは全員をすぐに見せたいので待たずに反映する
+            if model.reviewer_filter_input.trim().is_empty() {
+                model.reviewer_filter_timeout = None;
+                model.reviewer_filter.clear();
+                sync_url(model);
+            } else {
+                model.reviewer_filter_timeout = Some(orders.perform_cmd_with_handle(
+                    cmds::timeout(REVIEWER_FILTER_DEBOUNCE_MS, || Msg::ApplyReviewerFilter),
+                ));
+            }
+        }
+        Msg::ApplyReviewerFilter => {
+            model.reviewer_filter_timeout = None;
+            model.reviewer_filter = model.reviewer_filter_input.clone();
+            sync_url(model);
+        }
+        Msg::ChangeMinPrs(value) => {
+            let value = value.trim();
+            if value.is_empty() {
+                model.min_prs = 0;
+            } else if let Ok(min_prs) = value.parse::<usize>() {
+                model.min_prs = min_prs;
+            }
+        }
+        Msg::SelectRepository(full_name) => {
+            model.selected_repository = full_name;
+            sync_url(model);
+        }
+        Msg::ToggleCompact => {
+            model.settings.density = match model.settings.density {
+                Density::Comfortable => Density::Compact,
+                Density::Compact => Density::Comfortable,
+            };
+            save_settings(&model.settings);
+        }
+        Msg::ChangeLayout(key) => {
+            if let Some(&layout) = Layout::ALL.iter().find(|layout| layout.key() == key) {
+                model.layout = layout;
+                model.settings.layout = layout;
+                save_settings(&model.settings);
+                sync_url(model);
+            }
+        }
+        Msg::ChangeChipSort(key) => {
+            if let Some(&chip_sort) = ChipSort::ALL
+                .iter()
+                .find(|chip_sort| chip_sort.key() == key)
+            {
+                model.settings.chip_sort = chip_sort;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ChangeRepositorySort(key) => {
+            if let Some(&repository_sort) = RepositorySort::ALL
+                .iter()
+                .find(|repository_sort| repository_sort.key() == key)
+            {
+                model.settings.repository_sort = repository_sort;
+                save_settings(&model.settings);
+            }
+        }
+        Msg::ToggleAttentionFilter(full_name) => {
+            if model.attention_repository.as_ref() == Some(&full_name) {
+                model.attention_repository = None;
+            } else {
+                model.attention_repository = Some(full_name);
+            }
+        }
+        Msg::DismissError => {
+            model.fetch_error = None;
+            model.fetch_error_timeout = None;
+        }
+        Msg::DismissFetchWarnings => {
+            if let Some(organization) = &mut model.organization {
+                organization.warnings.clear();
+            }
+        }
+        Msg::OpenAll(owner, login) => {
+            if let Some(organization) = &model.organization {
+                let urls: Vec<&str> = organization
+                    .repositories
+                    .iter()
+                    .filter(|repository| repository.owner() == owner)
+                    .filter_map(|repository| {
+                        let reviewer = repository.reviewer(&login)?;
+                        Some(repository.assigned_pull_requests(reviewer))
+                    })
+                    .flatten()
+                    .map(|pull_request| pull_request.url.as_str())
+                    .collect();
+                // 一度に大量のタブを開くとポップアップブロックに引っかかるので、多いときは確認する
+                if urls.len() > OPEN_ALL_CONFIRM_THRESHOLD
+                    && !window()
+                        .confirm_with_message(&i18n::confirm_open_all(
+                            model.settings.lang,
+                            urls.len(),
+                        ))
+                        .unwrap_or(false)
+                {
+                    return;
+                }
+                for url in urls {
+                    window().open_with_url_and_target(url, "_blank").ok();
+                }
+            }
+        }
+        Msg::CopyPlainTable => {
+            if let Some(organization) = &model.organization {
+                model.copy_failed = false;
+                copy_to_clipboard(export::plain_text_table(organization, model), None, orders);
+            }
+        }
+        Msg::CopyMarkdown => {
+            if let Some(organization) = &model.organization {
+                model.copy_failed = false;
+                copy_to_clipboard(export::markdown_table(organization, model), None, orders);
+            }
+        }
+        Msg::ExportCsv => {
+            if let Some(organization) = &model.organization {
+                let file_name = format!(
+                    "reviews-{}-{}.csv",
+                    organization.names.join("-"),
+                    file_name_timestamp()
+                );
+                model.download_failed =
+                    download(&export::csv(organization, model), &file_name, "text/csv").is_err();
+            }
+        }
+        Msg::CopyFailed => model.copy_failed = true,
+        Msg::CopyLink(url) => {
+            model.copy_failed = false;
+            copy_to_clipboard(url.clone(), Some(Msg::LinkCopied(url)), orders);
+        }
+        Msg::LinkCopied(url) => {
+            model.copied_link = Some(url);
+            model.copied_link_timeout = Some(
+                orders.perform_cmd_with_handle(cmds::timeout(COPIED_LINK_DISPLAY_MS, || {
+                    Msg::ClearCopiedLink
+                })),
+            );
+        }
+        Msg::ClearCopiedLink => {
+            model.copied_link = None;
+            model.copied_link_timeout = None;
+        }
+        Msg::ClockTicked => {
+            if model.fetched_at.is_none() {
+                orders.skip();
+            }
+        }
+        Msg::BoardScrolled => {
+            let container = match model.board_container.get() {
+                Some(container) => container,
+                None => {
+                    orders.skip();
+                    return;
+                }
+            };
+            let column_count = model
+                .organization
+                .as_ref()
+                .map_or(0, |organization| organization.repositories.len());
+            // 行を数えるには絞り込みを全部やり直すことになるので、スクロールのたびには数えず上限で代える
+            let row_count_bound = model.organization.as_ref().map_or(0, |organization| {
+                organization
+                    .repositories
+                    .iter()
+                    .map(|repository| repository.reviewers.len())
+                    .sum::<usize>()
+                    + 1
+                    // 担当PRのないメンバーもboard_rowsで行になるので、その分も足す
+                    + if model.settings.show_all_members {
+                        organization.members.len()
+                    } else {
+                        0
+                    }
+            });
+            let before = (
+                visible_columns(
+                    model.board_scroll_left,
+                    model.board_viewport_width,
+                    column_count,
+                ),
+                visible_rows(model, row_count_bound),
+            );
+            model.board_scroll_left = f64::from(container.scroll_left());
+            model.board_viewport_width = f64::from(container.client_width());
+            model.board_scrolled_past = (-container.get_bounding_client_rect().top()).max(0.0);
+            model.board_viewport_height = window_inner_height();
+            if let Some(row_height) = rendered_row_height() {
+                model.board_row_height = row_height;
+            }
+            let after = (
+                visible_columns(
+                    model.board_scroll_left,
+                    model.board_viewport_width,
+                    column_count,
+                ),
+                visible_rows(model, row_count_bound),
+            );
+            // 描画する列や行が変わらないスクロールでは再描画しない
+            if before == after {
+                orders.skip();
+            }
+        }
+    }
+}
+
+// 成功したらcopiedを送る。失敗はどのコピーでもCopyFailedで知らせる
+fn copy_to_clipboard(text: String, copied: Option<Msg>, orders: &mut impl Orders<Msg>) {
+    let promise = window().navigator().clipboard().write_text(&text);
+    orders.perform_cmd(async move {
+        match JsFuture::from(promise).await {
+            Ok(_) => copied,
+            Err(_) => Some(Msg::CopyFailed),
+        }
+    });
+}
+
+const SHORTCUT_KEYS: [&str; 4] = ["r", "/", "?", "Escape"];
+
+// 入力欄で文字を打っているときや、ブラウザのショートカット（Ctrl+Rなど）のキーは横取りしない
+fn shortcut_key(event: web_sys::KeyboardEvent) -> Option<String> {
+    if event.ctrl_key() || event.meta_key() || event.alt_key() {
+        return None;
+    }
+    let is_editing = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || element.is_content_editable()
+        });
+    let key = event.key();
+    if is_editing || !SHORTCUT_KEYS.contains(&key.as_str()) {
+        return None;
+    }
+    // フォーカスした絞り込み欄に / が入力されないようにする
+    if key == "/" {
+        event.prevent_default();
+    }
+    Some(key)
+}
+
+fn window_inner_height() -> f64 {
+    window()
+        .inner_height()
+        .ok()
+        .and_then(|height| height.as_f64())
+        .unwrap_or_default()
+}
+
+fn rendered_row_height() -> Option<f64> {
+    let rows = document()
+        .query_selector_all(".board tbody tr.reviewer")
+        .ok()?;
+    let heights: Vec<f64> = (0..rows.length())
+        .filter_map(|index| rows.item(index))
+        .filter_map(|row| row.dyn_into::<web_sys::HtmlElement>().ok())
+        .map(|row| f64::from(row.offset_height()))
+        .collect();
+    if heights.is_empty() {
+        return None;
+    }
+    Some(heights.iter().sum::<f64>() / heights.len() as f64)
+}
+
+// Blobのリンクを作ってクリックし、ブラウザーのダウンロードとして保存させる
+fn download(text: &str, file_name: &str, mime_type: &str) -> Result<(), JsValue> {
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(
+        &js_sys::Array::of1(&JsValue::from_str(text)),
+        &options,
+    )?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let anchor: web_sys::HtmlAnchorElement = document().create_element("a")?.unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    web_sys::Url::revoke_object_url(&url)
+}
+
+// 20261014-093000 のような、ファイル名に使える現在時刻（UTC）
+fn file_name_timestamp() -> String {
+    let iso: String = js_sys::Date::new_0().to_iso_string().into();
+    iso.chars()
+        .take(19)
+        .filter(|c| *c != '-' && *c != ':')
+        .map(|c| if c == 'T' { '-' } else { c })
+        .collect()
+}
+
+// スクロール位置から描画する列の範囲を求める。先頭のレビュアー列は固定表示なのでその分を除く
+fn visible_columns(scroll_left: f64, viewport_width: f64, column_count: usize) -> Range<usize> {
+    let first = (scroll_left / BOARD_COLUMN_WIDTH_PX).floor() as usize;
+    let last = ((scroll_left + viewport_width - BOARD_FIXED_COLUMNS_WIDTH_PX)
+        / BOARD_COLUMN_WIDTH_PX)
+        .ceil()
+        .max(0.0) as usize;
+    let start = first
+        .saturating_sub(BOARD_COLUMN_OVERSCAN)
+        .min(column_count);
+    let end = last
+        .saturating_add(BOARD_COLUMN_OVERSCAN)
+        .min(column_count)
+        .max(start);
+    start..end
+}
+
+// スクロール位置から描画する行の範囲を求める。行数が少なければ全部描画する
+fn visible_rows(model: &Model, row_count: usize) -> Range<usize> {
+    if row_count < BOARD_VIRTUALIZED_MIN_ROWS {
+        return 0..row_count;
+    }
+    let row_height = model.board_row_height.max(1.0);
+    let first = (model.board_scrolled_past / row_height).floor() as usize;
+    let last =
+        ((model.board_scrolled_past + model.board_viewport_height) / row_height).ceil() as usize;
+    let start = first.saturating_sub(BOARD_ROW_OVERSCAN).min(row_count);
+    let end = last
+        .saturating_add(BOARD_ROW_OVERSCAN)
+        .min(row_count)
+        .max(start);
+    start..end
+}
+
+// "https://github.com/MyOrg/" のように貼り付けられた入力からOrganization名だけを取り出す。
+// GitHubのOrganization名は大文字小文字を区別しないので、入力された表記のまま使う
+fn normalize_organization(input: &str) -> String {
+    let mut organization = input.trim();
+    for prefix in ["https://", "http://", "www.", "github.com/", "orgs/"] {
+        if let Some(rest) = strip_prefix_ignore_ascii_case(organization, prefix) {
+            organization = rest;
+        }
+    }
+    organization
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn strip_prefix_ignore_ascii_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = value.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&value[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn set_organization(model: &mut Model, organization: Organization, fetched_at: String) {
+    model.capacities = organization
+        .owners()
+        .into_iter()
+        .map(|owner| {
+            let capacities = LocalStorage::get(capacities_storage_key(owner)).unwrap_or_default();
+            (owner.to_string(), capacities)
+        })
+        .collect();
+    model.organization = Some(organization);
+    model.fetched_at = Some(fetched_at);
+}
+
+fn fetch_pull_requests(
+    repositories: Vec<Repository>,
+    model: &Model,
+    orders: &mut impl Orders<Msg>,
+) {
+    let generation = model.fetch_generation;
+    let msg_sender = orders.msg_sender();
+    let on_progress = {
+        let msg_sender = msg_sender.clone();
+        move |progress| msg_sender(Some(Msg::FetchProgress(generation, progress)))
+    };
+    let on_repository =
+        move |repository| msg_sender(Some(Msg::RepositoryDataFetched(generation, repository)));
+    orders.perform_cmd(
+        fetch_organization_data(
+            model.client.clone(),
+            repositories,
+            model.form.clone(),
+            FetchOptions::from_model(model),
+            model.response_cache.clone(),
+            on_progress,
+            on_repository,
+        )
+        .map(move |result| Msg::DataFetched(generation, result)),
+    );
+}
+
+fn handle_fetch_error(err: FetchError, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    model.loading = false;
+    model.fetch_progress = None;
+    model.refresh_scroll = None;
+    // 一度も取得し終えていないなら、途中まで反映した不完全なデータは残さない
+    if model.fetched_at.is_none() {
+        model.organization = None;
+    }
+    // トークン切れはフォームから回復できるので、トークン欄に誘導する
+    if !model.kiosk && err == FetchError::Auth {
+        model.token_expired = true;
+        orders.after_next_render(|_| Msg::RevealTokenInput);
+    } else {
+        if let FetchError::SecondaryRateLimit { retry_at } = err {
+            let delay = (retry_at * 1000.0 - js_sys::Date::now()).max(0.0) as u32;
+            model.auto_retry =
+                Some(orders.perform_cmd_with_handle(cmds::timeout(delay, || Msg::FetchData)));
+        }
+        // 前回のデータが表示されていて次の取得で解消しそうなエラーなら、残り続けないよう自動で閉じる。
+        // トークンやレート制限のように対処が必要なエラーは、閉じるまで表示しておく
+        model.fetch_error_timeout =
+            (model.organization.is_some() && err.is_retryable() && !err.affects_all_requests())
+                .then(|| {
+                    orders.perform_cmd_with_handle(cmds::timeout(FETCH_ERROR_DISMISS_MS, || {
+                        Msg::DismissError
+                    }))
+                });
+        model.fetch_error = Some(err);
+    }
+}
+
+fn capacities_storage_key(organization_name: &str) -> String {
+    format!(
+        "{}{}",
+        CAPACITIES_STORAGE_KEY_PREFIX,
+        organization_name.to_lowercase()
+    )
+}
+
+fn parse_load_weight(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|weight| weight.is_finite() && *weight >= 0.0)
+}
+
+fn save_settings(settings: &Settings) {
+    // 容量がいっぱいでも、保存できないのは次回の起動時だけなので表示は続ける
+    LocalStorage::insert(SETTINGS_STORAGE_KEY, settings).ok();
+}
+
+fn remember_organization(model: &mut Model) {
+    let organization = model.form.organization.trim();
+    if organization.is_empty() {
+        return;
+    }
+    let history = &mut model.organization_history;
+    history.retain(|entry| !entry.eq_ignore_ascii_case(organization));
+    history.insert(0, organization.to_string());
+    history.truncate(ORGANIZATION_HISTORY_LIMIT);
+    LocalStorage::insert(ORGANIZATION_HISTORY_STORAGE_KEY, history).ok();
+}
+
+fn save_watched_pull_requests(watched_pull_requests: &[u64]) {
+    LocalStorage::insert(WATCHED_PULL_REQUESTS_STORAGE_KEY, watched_pull_requests).ok();
+}
+
+fn elapsed_millis(created_at: &str) -> f64 {
+    (js_sys::Date::now() - js_sys::Date::parse(created_at)).max(0.0)
+}
+
+// created_atからの経過時間を "3d" のような短い表記にする
+fn age_label(created_at: &str) -> String {
+    let elapsed_minutes = (elapsed_millis(created_at) / 60_000.0) as u64;
+    match elapsed_minutes {
+        minutes if minutes < 60 => format!("{}m", minutes),
+        minutes if minutes < 60 * 24 => format!("{}h", minutes / 60),
+        minutes => format!("{}d", minutes / (60 * 24)),
+    }
+}
+
+// 相対表記だけでは正確な時刻がわからないので、ツールチップ用にIntlで表示言語の日時にする
+fn absolute_time_label(created_at: &str, lang: Lang) -> String {
+    js_sys::Date::new(&JsValue::from_str(created_at))
+        .to_locale_string(lang.code(), &JsValue::UNDEFINED)
+        .into()
+}
+
+// 要対応とみなすシグナル。滞留しているか、CIが失敗しているか
+fn needs_attention(pull_request: &PullRequest, stale_days: u32) -> bool {
+    is_stale(pull_request, stale_days) || pull_request.ci_status == Some(CiStatus::Failure)
+}
+
+// クローズ済みのPRはもう待たせていないので、滞留やSLA超過とはみなさない
+fn is_stale(pull_request: &PullRequest, stale_days: u32) -> bool {
+    !pull_request.closed
+        && elapsed_millis(&pull_request.created_at) >= f64::from(stale_days) * MILLIS_PER_DAY
+}
+
+fn breaches_sla(pull_request: &PullRequest, sla_hours: u32) -> bool {
+    !pull_request.closed
+        && elapsed_millis(&pull_request.created_at) >= f64::from(sla_hours) * MILLIS_PER_HOUR
+}
+
+fn pushed_recently(repository: &Repository, recent_days: u32) -> bool {
+    repository.pushed_at.as_deref().is_some_and(|pushed_at| {
+        elapsed_millis(pushed_at) <= f64::from(recent_days) * MILLIS_PER_DAY
+    })
+}
+
+// パスワードや途中で切れたトークンを貼り付けていないか確かめるための、GitHubのトークンの接頭辞
+const KNOWN_TOKEN_PREFIXES: [&str; 5] = ["ghp_", "github_pat_", "gho_", "ghu_", "ghs_"];
+
+fn has_known_token_prefix(token: &str) -> bool {
+    KNOWN_TOKEN_PREFIXES
+        .iter()
+        .any(|prefix| token.starts_with(prefix))
+}
+
+fn github_headers(token: &str) -> Result<HeaderMap, FetchError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::AUTHORIZATION,
+        format!("Bearer {}", token.trim())
+            .parse()
+            .map_err(|_| FetchError::InvalidToken)?,
+    );
+    headers.insert(header::USER_AGENT, "my-app".parse().unwrap());
+    Ok(headers)
+}
+
+// ページングされた一覧APIを、Linkヘッダーの rel="next" がなくなるまで辿ってすべて取得する
+async fn fetch_all_pages<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+) -> Result<Vec<T>, FetchError> {
+    let mut items = Vec::new();
+    let mut next_url = Some(url.to_string());
+    while let Some(url) = next_url {
+        let (page_items, next) = fetch_page(client, &url, headers, cache).await?;
+        items.extend(page_items);
+        next_url = next;
+    }
+    Ok(items)
+}
+
+// Link: <https://api.github.com/...&page=2>; rel="next", <...>; rel="last" から次のページのURLを取り出す
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';').map(str::trim);
+        let url = segments.next()?.strip_prefix('<')?.strip_suffix('>')?;
+        segments
+            .any(|segment| segment == r#"rel="next""#)
+            .then(|| url.to_string())
+    })
+}
+
+// 一時的な失敗は間隔を広げながら再試行し、それでも失敗したら最後のエラーを返す
+async fn fetch_page<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+) -> Result<(Vec<T>, Option<String>), FetchError> {
+    let JsonResponse {
+        value,
+        next_url,
+        status,
+        body_snippet,
+    } = fetch_json(client, url, headers, cache, parse_json_array).await?;
+    let items = json_array_items(&value).map_err(|kind| FetchError::Parse {
+        url: url.to_string(),
+        kind,
+        status,
+        body_snippet: body_snippet.unwrap_or_else(|| error::body_snippet(&value.to_string())),
+    })?;
+    Ok((items, next_url))
+}
+
+// PRの詳細など、一覧ではなく1件のオブジェクトを返すAPIを取得する
+async fn fetch_object(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+) -> Result<serde_json::Value, FetchError> {
+    Ok(fetch_json(client, url, headers, cache, parse_json_object)
+        .await?
+        .value)
+}
+
+// APIのレスポンス。中身が想定と違ったときのエラーに使えるよう、ステータスと本文の先頭も持つ。
+// キャッシュから読んだ場合は元のレスポンスがないので、どちらもNone
+struct JsonResponse {
+    value: serde_json::Value,
+    // 一覧系APIの次のページのURL
+    next_url: Option<String>,
+    status: Option<u16>,
+    body_snippet: Option<String>,
+}
+
+async fn fetch_json(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+    parse: fn(&str) -> Result<serde_json::Value, ParseError>,
+) -> Result<JsonResponse, FetchError> {
+    let mut delays = RETRY_DELAYS_MS.iter();
+    loop {
+        match fetch_json_once(client, url, headers, cache, parse).await {
+            Err(err) if is_transient(&err) => match delays.next() {
+                Some(&delay) => cmds::timeout(delay, || ()).await,
+                None => return Err(err),
+            },
+            result => return result,
+        }
+    }
+}
+
+// wasm版のreqwestにはタイムアウトの設定がないので、タイマーと競争させて先に終わった方を採る
+async fn with_timeout<T>(
+    url: &str,
+    request: impl std::future::Future<Output = T>,
+) -> Result<T, FetchError> {
+    let timeout = cmds::timeout(REQUEST_TIMEOUT_MS, || ());
+    match future::select(Box::pin(request), Box::pin(timeout)).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(FetchError::Timeout {
+            url: url.to_string(),
+        }),
+    }
+}
+
+// 4xxは何度送っても同じ結果になるので、接続エラーと5xxだけを再試行の対象にする
+fn is_transient(err: &FetchError) -> bool {
+    match err {
+        FetchError::Network { .. } => true,
+        FetchError::Status { status, .. } => *status >= 500,
+        _ => false,
+    }
+}
+
+// APIのレスポンスと、一覧系APIなら次のページのURLを取得する。ステータス・Content-Type・JSONの形をparseで確認し、
+// 想定外の場合は原因がわかるエラーにする
+async fn fetch_json_once(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+    parse: fn(&str) -> Result<serde_json::Value, ParseError>,
+) -> Result<JsonResponse, FetchError> {
+    let network_error = |_| FetchError::Network {
+        url: url.to_string(),
+    };
+    let mut request = client.get(url).headers(headers.clone());
+    // 前回と変わっていなければ304が返り、レート制限にも数えられない
+    if let Some(cached) = cache.borrow().pages.get(url) {
+        request = request.header(header::IF_NONE_MATCH, &cached.etag);
+    }
+    let response = with_timeout(url, request.send())
+        .await?
+        .map_err(network_error)?;
+    let status = response.status();
+    let response_header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let rate_limit_remaining = response_header("x-ratelimit-remaining");
+    let rate_limit_reset = response_header("x-ratelimit-reset");
+    let rate_limit_budget = (|| {
+        Some(RateLimitBudget {
+            remaining: rate_limit_remaining.as_deref()?.parse().ok()?,
+            limit: response_header("x-ratelimit-limit")?.parse().ok()?,
+            reset: rate_limit_reset.as_deref()?.parse().ok()?,
+        })
+    })();
+    if let Some(budget) = rate_limit_budget {
+        cache.borrow_mut().record_rate_limit(budget);
+    }
+    if status == reqwest::StatusCode::NOT_MODIFIED {
+        if let Some(cached) = cache.borrow().pages.get(url) {
+            return Ok(JsonResponse {
+                value: cached.items.clone(),
+                next_url: cached.next_url.clone(),
+                status: None,
+                body_snippet: None,
+            });
+        }
+    }
+    let content_type = response_header(header::CONTENT_TYPE.as_str()).unwrap_or_default();
+    let sso = response_header("x-github-sso");
+    let retry_after = response_header(header::RETRY_AFTER.as_str());
+    let next_url = response_header(header::LINK.as_str())
+        .as_deref()
+        .and_then(next_page_url);
+    let etag = response_header(header::ETAG.as_str());
+    let body = with_timeout(url, response.text())
+        .await?
+        .map_err(network_error)?;
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(FetchError::Auth);
+    }
+    if status == reqwest::StatusCode::FORBIDDEN {
+        // X-GitHub-SSO: required; url=https://github.com/orgs/.../sso?authorization_request=...
+        if let Some(sso) = sso {
+            return Err(FetchError::Sso {
+                authorize_url: sso
+                    .split(';')
+                    .find_map(|part| part.trim().strip_prefix("url="))
+                    .map(str::to_string),
+            });
+        }
+        // 権限の足りないFine-grainedトークンでは "Resource not accessible by personal access token" が返る
+        if github_error_message(&body)
+            .is_some_and(|message| message.starts_with("Resource not accessible by"))
+        {
+            return Err(FetchError::InsufficientScope {
+                url: url.to_string(),
+            });
+        }
+    }
+    if status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+    {
+        if rate_limit_remaining.as_deref() == Some("0") {
+            return Err(FetchError::RateLimit {
+                reset: rate_limit_reset.and_then(|reset| reset.parse().ok()),
+            });
+        }
+        // 短時間に集中したリクエストへの二次レート制限は、残数ではなくRetry-After（秒）で知らされる
+        if let Some(retry_after) = retry_after.and_then(|seconds| seconds.parse::<f64>().ok()) {
+            return Err(FetchError::SecondaryRateLimit {
+                retry_at: js_sys::Date::now() / 1000.0 + retry_after,
+            });
+        }
+    }
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Err(FetchError::NotFound {
+            url: url.to_string(),
+        });
+    }
+    if !status.is_success() {
+        return Err(FetchError::Status {
+            url: url.to_string(),
+            status: status.as_u16(),
+            message: github_error_message(&body),
+        });
+    }
+    let parse_error = |kind| FetchError::Parse {
+        url: url.to_string(),
+        kind,
+        status: Some(status.as_u16()),
+        body_snippet: error::body_snippet(&body),
+    };
+    if !content_type.starts_with("application/json") {
+        return Err(parse_error(ParseError::ContentType(content_type)));
+    }
+    let value = parse(&body).map_err(parse_error)?;
+    match etag {
+        Some(etag) => {
+            cache.borrow_mut().pages.insert(
+                url.to_string(),
+                CachedPage {
+                    etag,
+                    items: value.clone(),
+                    next_url: next_url.clone(),
+                },
+            );
+        }
+        None => {
+            cache.borrow_mut().pages.remove(url);
+        }
+    }
+    Ok(JsonResponse {
+        value,
+        next_url,
+        status: Some(status.as_u16()),
+        body_snippet: Some(error::body_snippet(&body)),
+    })
+}
+
+fn parse_json_array(body: &str) -> Result<serde_json::Value, ParseError> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|err| ParseError::InvalidJson(err.to_string()))?;
+    match value {
+        serde_json::Value::Array(_) => Ok(value),
+        // {"message": "..."} のようなエラーのオブジェクトなら、空の一覧として扱わずにメッセージを伝える
+        serde_json::Value::Object(ref object) => Err(ParseError::ExpectedArrayButObject(
+            object
+                .get("message")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+        )),
+        _ => Err(ParseError::ExpectedArray),
+    }
+}
+
+fn parse_json_object(body: &str) -> Result<serde_json::Value, ParseError> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|err| ParseError::InvalidJson(err.to_string()))?;
+    match value {
+        serde_json::Value::Object(_) => Ok(value),
+        _ => Err(ParseError::ExpectedObject),
+    }
+}
+
+fn json_array_items<T: DeserializeOwned>(value: &serde_json::Value) -> Result<Vec<T>, ParseError> {
+    Vec::<T>::deserialize(value).map_err(|err| ParseError::UnexpectedItems(err.to_string()))
+}
+
+// GitHubのエラーレスポンス {"message": "..."} からメッセージを取り出す
+fn github_error_message(body: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()?
+        .get("message")?
+        .as_str()
+        .map(str::to_string)
+}
+
+// 自分の行を目立たせるためだけに使うので、取得できなくてもエラーは表示しない
+async fn fetch_viewer_login(
+    client: reqwest::Client,
+    token: String,
+    cache: SharedResponseCache,
+) -> Option<String> {
+    let headers = github_headers(&token).ok()?;
+    let user = fetch_object(&client, "https://api.github.com/user", &headers, &cache)
+        .await
+        .ok()?;
+    user["login"].as_str().map(str::to_string)
+}
+
+// 入力の手間を省くためだけに使うので、取得できなくてもエラーは表示せず空の一覧にする
+async fn fetch_user_organizations(
+    client: reqwest::Client,
+    token: String,
+    cache: SharedResponseCache,
+) -> Vec<String> {
+    let headers = match github_headers(&token) {
+        Ok(headers) => headers,
+        Err(_) => return Vec::new(),
+    };
+    let url = format!("https://api.github.com/user/orgs?per_page={}", PER_PAGE);
+    let organizations: Vec<serde_json::Value> =
+        match fetch_all_pages(&client, &url, &headers, &cache).await {
+            Ok(organizations) => organizations,
+            Err(_) => return Vec::new(),
+        };
+    organizations
+        .iter()
+        .filter_map(|organization| organization["login"].as_str().map(str::to_string))
+        .collect()
+}
+
+// 全Organizationのリポジトリ一覧と、一覧を取得できなかったOrganizationのエラーを返す。
+// 名前の打ち間違いなどで1つのOrganizationが失敗しても、ほかのOrganizationは表示する
+async fn fetch_repositories(
+    client: reqwest::Client,
+    form: Form,
+    cache: SharedResponseCache,
+) -> Result<(Vec<Repository>, Vec<FetchError>), FetchError> {
+    let headers = github_headers(&form.token)?;
+    let mut repositories = Vec::new();
+    let mut warnings = Vec::new();
+    for organization_name in form.organization_names() {
+        match fetch_organization_repositories(
+            &client,
+            &headers,
+            &cache,
+            organization_name,
+            &form.team_slug,
+        )
+        .await
+        {
+            // Fine-grainedトークンでOrganizationを対象にし忘れると、エラーではなく空の一覧が返る。
+            // Organization自体は見えるなら、リポジトリがないのではなく権限が足りないと判断する
+            Ok(organization_repositories)
+                if organization_repositories.is_empty() && form.team_slug.is_empty() =>
+            {
+                let organization_url = format!("https://api.github.com/orgs/{}", organization_name);
+                if fetch_object(&client, &organization_url, &headers, &cache)
+                    .await
+                    .is_ok()
+                {
+                    warnings.push(FetchError::InsufficientScope {
+                        url: format!("https://api.github.com/orgs/{}/repos", organization_name),
+                    });
+                }
+            }
+            Ok(organization_repositories) => repositories.extend(organization_repositories),
+            Err(err) if err.affects_all_requests() => return Err(err),
+            Err(err) => warnings.push(err),
+        }
+    }
+    // どのOrganizationも取得できなかった場合は、空の表ではなくエラーとして表示する
+    if repositories.is_empty() && warnings.len() == form.organization_names().len() {
+        return Err(warnings.remove(0));
+    }
+    // トークンに権限がなくプライベートなリポジトリが見えない場合など。レビュー待ちがないのとは区別する
+    if repositories.is_empty() && warnings.is_empty() {
+        return Err(FetchError::Empty);
+    }
+    Ok((repositories, warnings))
+}
+
+async fn fetch_organization_repositories(
+    client: &reqwest::Client,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+    organization_name: &str,
+    team_slug: &str,
+) -> Result<Vec<Repository>, FetchError> {
+    // チームはOrganizationにしかないので、個人のアカウントへの切り替えはしない
+    if !team_slug.is_empty() {
+        let team_repositories_url = format!(
+            "https://api.github.com/orgs/{}/teams/{}/repos?per_page={}",
+            organization_name, team_slug, PER_PAGE
+        );
+        return fetch_all_pages(client, &team_repositories_url, headers, cache).await;
+    }
+    // 最近pushされた順に並べておくと、件数を制限したときにも動きのあるリポジトリが残る
+    let repositories_url = format!(
+        "https://api.github.com/orgs/{}/repos?sort=pushed&per_page={}",
+        organization_name, PER_PAGE
+    );
+    match fetch_all_pages(client, &repositories_url, headers, cache).await {
+        // Organizationではなく個人のアカウント名が入力された場合は、そのユーザーのリポジトリを表示する
+        Err(err @ FetchError::NotFound { .. }) => {
+            let user_repositories_url = format!(
+                "https://api.github.com/users/{}/repos?sort=pushed&per_page={}",
+                organization_name, PER_PAGE
+            );
+            match fetch_all_pages(client, &user_repositories_url, headers, cache).await {
+                // どちらも見つからなければ、入力どおりOrganizationとして見つからなかったことを伝える
+                Err(FetchError::NotFound { .. }) => Err(err),
+                result => result,
+            }
+        }
+        result => result,
+    }
+}
+
+// 1つのリポジトリについて、オープンなPRとそのレビュアーを取得する
+async fn fetch_repository_data(
+    client: &reqwest::Client,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+    repository: &mut Repository,
+    options: &FetchOptions,
+) -> Result<(), FetchError> {
+    let mut pulls: Vec<serde_json::Value> = Vec::new();
+    if options.pull_request_state != PullRequestState::Closed {
+        let pulls_url = format!(
+            "https://api.github.com/repos/{}/pulls?state=open&per_page={}",
+            repository.full_name, PER_PAGE
+        );
+        pulls = fetch_all_pages(client, &pulls_url, headers, cache).await?;
+    }
+    // クローズ済みは過去のPRをすべて辿ると膨大になるので、最近更新された1ページ分だけにする。
+    // Allでもオープンなほうは上ですべて取得しているので、古いままのオープンなPRは落ちない
+    if options.pull_request_state != PullRequestState::Open {
+        let pulls_url = format!(
+            "https://api.github.com/repos/{}/pulls?state=closed&sort=updated&direction=desc&per_page={}",
+            repository.full_name, PER_PAGE
+        );
+        pulls.extend(fetch_page(client, &pulls_url, headers, cache).await?.0);
+    }
+    // 一覧のAPIにはmergeableが含まれないので、オープンなPRだけ詳細を取得して置き換える
+    if options.fetch_mergeable {
+        for pull in pulls.iter_mut() {
+            if pull["state"].as_str() != Some("open") {
+                continue;
+            }
+            let url = match pull["url"].as_str() {
+                Some(url) => url.to_string(),
+                None => continue,
+            };
+            *pull = fetch_object(client, &url, headers, cache).await?;
+        }
+    }
+    let mut reviews_by_pull = HashMap::new();
+    if options.fetch_reviews {
+        for pull in &pulls {
+            let (id, url) = match (pull["id"].as_u64(), pull["url"].as_str()) {
+                (Some(id), Some(url)) => (id, url),
+                _ => continue,
+            };
+            let reviews_url = format!("{}/reviews?per_page={}", url, PER_PAGE);
+            let reviews: Vec<serde_json::Value> =
+                fetch_all_pages(client, &reviews_url, headers, cache).await?;
+            reviews_by_pull.insert(id, reviews);
+        }
+    }
+    aggregate_pull_requests(repository, &pulls, &reviews_by_pull, options);
+    if options.fetch_ci_status {
+        for pull_request in repository.pull_requests.iter_mut() {
+            if pull_request.closed || pull_request.head_sha.is_empty() {
+                continue;
+            }
+            let status_url = format!(
+                "https://api.github.com/repos/{}/commits/{}/status",
+                repository.full_name, pull_request.head_sha
+            );
+            // ステータスが読めなくてもPRの表示には困らないので、印を付けないだけにする
+            pull_request.ci_status = match fetch_object(client, &status_url, headers, cache).await {
+                Ok(status) => CiStatus::from_combined_status(&status),
+                // Fine-grainedトークンに「Commit statuses: Read」がないだけなら、ほかの取得は続ける
+                Err(FetchError::InsufficientScope { .. }) => None,
+                Err(err) if err.affects_all_requests() => return Err(err),
+                Err(_) => None,
+            };
+        }
+    }
+    Ok(())
+}
+
+fn parse_pull_request(pull: &serde_json::Value, repo_name: &str) -> Option<PullRequest> {
+    Some(PullRequest {
+        id: pull["id"].as_u64()?,
+        number: pull["number"].as_u64()?,
+        title: pull["title"].as_str().unwrap_or_default().to_string(),
+        repo_name: repo_name.to_string(),
+        url: pull["html_url"].as_str()?.to_string(),
+        author: pull["user"]["login"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        created_at: pull["created_at"].as_str()?.to_string(),
+        draft: pull["draft"].as_bool().unwrap_or_default(),
+        closed: pull["state"].as_str() == Some("closed"),
+        mergeable: pull["mergeable"].as_bool(),
+        head_sha: pull["head"]["sha"].as_str().unwrap_or_default().to_string(),
+        ci_status: None,
+        changed_lines: pull["additions"]
+            .as_u64()
+            .zip(pull["deletions"].as_u64())
+            .map(|(additions, deletions)| additions + deletions),
+        labels: pull["labels"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|label| {
+                Some((
+                    label["name"].as_str()?.to_string(),
+                    label["color"].as_str().unwrap_or("ededed").to_string(),
+                ))
+            })
+            .collect(),
+    })
+}
+
+// 取得したPRとレビューの一覧から、リポジトリのPRとレビュアーごとの割り当てを組み立てる。
+// 通信とは切り離しておき、集計の規則だけをここにまとめる
+fn aggregate_pull_requests(
+    repository: &mut Repository,
+    pulls: &[serde_json::Value],
+    reviews_by_pull: &HashMap<u64, Vec<serde_json::Value>>,
+    options: &FetchOptions,
+) {
+    for pull in pulls {
+        // 想定外の形のPRが1件あっても表示全体は落とさず、そのPRだけ飛ばす
+        let pull_request = match parse_pull_request(pull, &repository.name) {
+            Some(pull_request) => pull_request,
+            None => continue,
+        };
+        let id = pull_request.id;
+        // ドラフトやクローズ済みはまだ/もうレビューを頼む必要がないので、レビュアーなしとはみなさない
+        let awaits_reviewer = !pull_request.draft && !pull_request.closed;
+        repository.pull_requests.push(pull_request);
+        let requested_logins: Vec<&str> = pull["requested_reviewers"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|user| user["login"].as_str())
+            .filter(|login| !options.is_hidden_bot(login))
+            .collect();
+        for &login in &requested_logins {
+            repository.assign(login, id);
+        }
+        for review in reviews_by_pull.get(&id).into_iter().flatten() {
+            // 削除されたユーザーのレビューはuserがnullになる
+            let reviewer_login = match review["user"]["login"].as_str() {
+                Some(login) if !options.is_hidden_bot(login) => login,
+                _ => continue,
+            };
+            let state = match review["state"].as_str() {
+                Some("APPROVED") => ReviewState::Approved,
+                Some("CHANGES_REQUESTED") => ReviewState::ChangesRequested,
+                _ => continue,
+            };
+            // 依頼が残っているなら、レビューしたあとにもう一度依頼された再レビュー
+            if requested_logins.contains(&reviewer_login) {
+                repository.mark_re_reviewed(reviewer_login, id);
+                continue;
+            }
+            // 同じPRに複数回レビューしていても1件として数え、再レビューとして覚えておく
+            if !repository.assign(reviewer_login, id) {
+                repository.mark_re_reviewed(reviewer_login, id);
+            }
+            repository.set_review_state(reviewer_login, id, state);
+        }
+        // レビュアーとしても割り当て済みの人は、レビュアーとしての表示を優先する
+        if options.use_assignees {
+            for assignee in pull["assignees"].as_array().into_iter().flatten() {
+                if let Some(login) = assignee["login"]
+                    .as_str()
+                    .filter(|login| !options.is_hidden_bot(login))
+                {
+                    if repository.assign(login, id) {
+                        repository.mark_assignee(login, id);
+                    }
+                }
+            }
+        }
+        // チームへのレビュー依頼は、個人と区別できるよう "@slug" の行にする
+        for team in pull["requested_teams"].as_array().into_iter().flatten() {
+            if let Some(slug) = team["slug"].as_str() {
+                repository.assign(&format!("@{}", slug), id);
+            }
+        }
+        // 誰にも割り当てられていないPRは表から消えて忘れられやすいので、専用の行に出す
+        if awaits_reviewer
+            && !repository
+                .reviewers
+                .values()
+                .any(|reviewer| reviewer.assigned_pull_request_ids.contains(&id))
+        {
+            repository.assign(UNASSIGNED_REVIEWER, id);
+        }
+    }
+}
+
+async fn fetch_organization_data(
+    client: reqwest::Client,
+    repositories: Vec<Repository>,
+    form: Form,
+    options: FetchOptions,
+    cache: SharedResponseCache,
+    on_progress: impl Fn(FetchProgress),
+    on_repository: impl Fn(Repository),
+) -> Result<Organization, FetchError> {
+    let headers = github_headers(&form.token)?;
+    let total = repositories.len();
+    let done = Cell::new(0);
+    // リポジトリごとの取得は同時に進める。GitHubは大量の同時リクエストを嫌うので数は絞り、
+    // 結果は元の並び順のまま受け取って表のレイアウトが取得のたびに変わらないようにする
+    // 途中で消されたリポジトリなどの失敗は警告にとどめ、ほかのリポジトリの結果は残す。
+    // トークンやレート制限のようにすべてのリクエストが失敗するエラーの場合は取得をやめる。
+    // 間隔を空ける設定のときは、同時に進めると間隔の意味がなくなるので1つずつ取得する
+    let concurrency = if options.throttle_ms > 0 {
+        1
+    } else {
+        CONCURRENT_REPOSITORY_FETCHES
+    };
+    let results: Vec<(Repository, Option<FetchError>)> = stream::iter(
+        repositories
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut repository)| {
+                let client = &client;
+                let headers = &headers;
+                let cache = &cache;
+                let done = &done;
+                let on_progress = &on_progress;
+                let on_repository = &on_repository;
+                let options = &options;
+                async move {
+                    if options.throttle_ms > 0 && index > 0 {
+                        cmds::timeout(options.throttle_ms, || ()).await;
+                    }
+                    on_progress(FetchProgress {
+                        done: done.get(),
+                        total,
+                        current_repository: repository.full_name.clone(),
+                    });
+                    let result =
+                        fetch_repository_data(client, headers, cache, &mut repository, options)
+                            .await;
+                    done.set(done.get() + 1);
+                    match result {
+                        Ok(()) => {
+                            on_repository(repository.clone());
+                            Ok((repository, None))
+                        }
+                        Err(err) if err.affects_all_requests() => Err(err),
+                        Err(err) => {
+                            // 途中までの結果は不完全なので表示しない
+                            repository.pull_requests.clear();
+                            repository.reviewers.clear();
+                            Ok((repository, Some(err)))
+                        }
+                    }
+                }
+            }),
+    )
+    .buffered(concurrency)
+    .try_collect()
+    .await?;
+    let (repositories, warnings): (Vec<Repository>, Vec<Option<FetchError>>) =
+        results.into_iter().unzip();
+    let mut warnings: Vec<FetchError> = warnings.into_iter().flatten().collect();
+    let mut members = Vec::new();
+    if options.show_all_members {
+        for organization_name in form.organization_names() {
+            match fetch_organization_members(
+                &client,
+                &headers,
+                &cache,
+                organization_name,
+                &form.team_slug,
+            )
+            .await
+            {
+                Ok(logins) => {
+                    // 行の (owner, login) はリポジトリのowner表記に合わせないと、同じ人が別の行に分かれてしまう
+                    let owner = repositories
+                        .iter()
+                        .map(Repository::owner)
+                        .find(|owner| owner.eq_ignore_ascii_case(organization_name))
+                        .unwrap_or(organization_name);
+                    members.extend(
+                        logins
+                            .into_iter()
+                            .filter(|login| !options.is_hidden_bot(login))
+                            .map(|login| (owner.to_string(), login)),
+                    );
+                }
+                // 個人のアカウントにはメンバーがいないので、レビュアーだけを表示する
+                Err(FetchError::NotFound { .. }) => {}
+                // 「Members: Read」がないトークンでも、レビュアーの表は出せるので警告にとどめる
+                Err(err @ FetchError::InsufficientScope { .. }) => warnings.push(err),
+                Err(err) if err.affects_all_requests() => return Err(err),
+                Err(err) => warnings.push(err),
+            }
+        }
+    }
+
+    Ok(Organization {
+        names: form
+            .organization_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        repositories,
+        members,
+        warnings,
+    })
+}
+
+async fn fetch_organization_members(
+    client: &reqwest::Client,
+    headers: &HeaderMap,
+    cache: &SharedResponseCache,
+    organization_name: &str,
+    team_slug: &str,
+) -> Result<Vec<String>, FetchError> {
+    // チームで絞り込んでいるときは、リポジトリと同じくチームのメンバーだけにする
+    let members_url = if team_slug.is_empty() {
+        format!(
+            "https://api.github.com/orgs/{}/members?per_page={}",
+            organization_name, PER_PAGE
+        )
+    } else {
+        format!(
+            "https://api.github.com/orgs/{}/teams/{}/members?per_page={}",
+            organization_name, team_slug, PER_PAGE
+        )
+    };
+    let members: Vec<serde_json::Value> =
+        fetch_all_pages(client, &members_url, headers, cache).await?;
+    Ok(members
+        .iter()
+        .filter_map(|member| member["login"].as_str().map(str::to_string))
+        .collect())
+}
+
+fn view(model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    div![
+        IF!(model.kiosk => style! { St::FontSize => "1.5em" }),
+        IF!(!model.kiosk => view_settings(model)),
+        h1![
+            i18n::app_title(lang),
+            IF!(!model.kiosk => button![
+                C!["toggle-help"],
+                style! { St::MarginLeft => em(0.5), St::FontSize => "50%" },
+                attrs! {
+                At::Title => i18n::keyboard_shortcuts(lang),
+                },
+                "?",
+                ev(Ev::Click, |_| Msg::ToggleHelp)
+            ]),
+        ],
+        IF!(model.show_help => view_help(lang)),
+        IF!(!model.kiosk => view_credentials_form(model)),
+        match &model.pending_repositories {
+            Some(repositories) if !model.kiosk => {
+                view_large_fetch_confirmation(
+                    repositories.len(),
+                    model.settings.large_organization_threshold,
+                    lang,
+                )
+            }
+            _ => empty![],
+        },
+        model
+            .fetch_error
+            .as_ref()
+            .map(|err| view_fetch_error(err, model)),
+        match &model.organization {
+            Some(organization) => {
+                div![
+                    p![
+                        i18n::organization_label(lang, &organization.names.join(", ")),
+                        IF!(organization.unique_pr_count() > 0 => span![
+                            C!["unique-pull-requests"],
+                            style! { St::MarginLeft => em(1) },
+                            i18n::pull_requests_awaiting_review(
+                                lang,
+                                organization.unique_pr_count(),
+                                organization.repositories_awaiting_review_count()
+                            )
+                        ]),
+                        model.fetched_at.as_ref().map(|fetched_at| span![
+                            C!["last-updated"],
+                            style! { St::MarginLeft => em(1), St::Color => "#6a737d" },
+                            attrs! {
+                            At::Title => absolute_time_label(fetched_at, lang),
+                            },
+                            if elapsed_millis(fetched_at) < 60_000.0 {
+                                i18n::updated_just_now(lang).to_string()
+                            } else {
+                                i18n::last_updated(lang, &age_label(fetched_at))
+                            }
+                        ]),
+                        IF!(model.loading => span![
+                            C!["refreshing"],
+                            style! { St::MarginLeft => em(1), St::Color => "#6a737d" },
+                            match &model.fetch_progress {
+                                Some(progress) => view_fetch_progress(progress, lang),
+                                None => i18n::refreshing(lang).to_string(),
+                            }
+                        ]),
+                        view_rate_limit_budget(model),
+                        IF!(!model.kiosk && !model.loading => button![
+                            C!["refresh"],
+                            style! { St::MarginLeft => em(1) },
+                            attrs! {
+                            At::Title => i18n::refresh_hint(lang),
+                            },
+                            i18n::refresh(lang),
+                            ev(Ev::Click, |_| Msg::Refresh)
+                        ]),
+                        IF!(!model.kiosk && !model.loading => button![
+                            C!["force-refresh"],
+                            style! { St::MarginLeft => em(1) },
+                            attrs! {
+                            At::Title => i18n::force_refresh_hint(lang),
+                            },
+                            i18n::force_refresh(lang),
+                            ev(Ev::Click, |_| Msg::ForceRefresh)
+                        ]),
+                    ],
+                    view_sla_summary(organization, model),
+                    view_self_review_note(organization, model),
+                    view_skipped_repositories_note(model),
+                    view_fetch_warnings(organization, model),
+                    view_watchlist(organization, model),
+                    IF!(!model.kiosk => view_exports(model)),
+                    IF!(!model.kiosk => view_reviewer_filter(model)),
+                    IF!(!model.kiosk => view_min_prs_input(model)),
+                    IF!(!model.kiosk => view_my_reviews(organization, model)),
+                    IF!(!model.kiosk => view_repository_select(organization, model)),
+                    // ヘッダーだけの空の表は壊れて見えるので、レビュー待ちがなければ代わりに案内を出す
+                    if organization.reviewer_keys().is_empty() {
+                        view_no_pending_reviews(model.settings.lang)
+                    } else if model.layout == Layout::ByRepository {
+                        view_board_by_repository(organization, model)
+                    } else {
+                        view_board(organization, model)
+                    },
+                ]
+            }
+            None if model.loading => p![match &model.fetch_progress {
+                Some(progress) => view_fetch_progress(progress, lang),
+                None => i18n::loading(lang).to_string(),
+            }],
+            // 大きなOrganizationの確認を待っている間は、確認のほうに答えてもらう
+            None if model.pending_repositories.is_some() => empty![],
+            // エラーは右下に重ねて表示するので、その間も取得を促す案内は出しておく
+            None => p![i18n::fetch_prompt(lang)],
+        }
+    ]
+}
+
+// エラーの種類ごとにアイコンと、その場でできるリカバリー操作を出し分ける
+fn view_fetch_error(err: &FetchError, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let icon = match err {
+        FetchError::InvalidToken | FetchError::Auth | FetchError::Sso { .. } => "🔒",
+        FetchError::RateLimit { .. } | FetchError::SecondaryRateLimit { .. } => "⏳",
+        FetchError::NotFound { .. } => "🔍",
+        FetchError::Network { .. } | FetchError::Timeout { .. } => "📡",
+        FetchError::Status { .. } | FetchError::Parse { .. } => "⚠️",
+        FetchError::Empty => "📭",
+        FetchError::InsufficientScope { .. } => "🔒",
+    };
+    // 表を見ながらでも気づけるよう、画面の右下に重ねて表示する
+    div![
+        C!["fetch-error"],
+        style! {
+            St::Position => "fixed",
+            St::Right => em(1),
+            St::Bottom => em(1),
+            St::ZIndex => 10,
+            St::MaxWidth => em(30),
+            St::Padding => "0.5em 1em",
+            St::Color => "#d73a49",
+            St::BackgroundColor => "#fff",
+            St::Border => "1px solid #d73a49",
+            St::BorderRadius => px(6),
+            St::BoxShadow => "0 4px 12px rgba(0, 0, 0, 0.15)",
+        },
+        IF!(!model.kiosk => button![
+            C!["dismiss-error"],
+            style! {
+                St::Float => "right",
+                St::MarginLeft => em(0.5),
+                St::Border => "none",
+                St::Background => "none",
+                St::Cursor => "pointer",
+            },
+            attrs! {
+            At::Title => i18n::dismiss(lang),
+            },
+            "×",
+            ev(Ev::Click, |_| Msg::DismissError)
+        ]),
+        p![format!("{} {}", icon, err.message(lang))],
+        match err {
+            FetchError::Sso {
+                authorize_url: Some(authorize_url),
+            } if !model.kiosk => a![
+                attrs! {
+                At::Href => authorize_url,
+                At::Target => "_blank",
+                At::Rel => "noopener noreferrer",
+                },
+                i18n::authorize_token(lang),
+            ],
+            _ => empty![],
+        },
+        IF!(!model.kiosk && err.needs_new_token() => button![
+            i18n::re_enter_token(lang),
+            ev(Ev::Click, |_| Msg::RevealTokenInput)
+        ]),
+        IF!(!model.kiosk && err.is_retryable() => button![
+            i18n::retry(lang),
+            ev(Ev::Click, |_| Msg::FetchData)
+        ]),
+    ]
+}
+
+fn view_fetch_progress(progress: &FetchProgress, lang: Lang) -> String {
+    i18n::loading_pull_requests(
+        lang,
+        &progress.current_repository,
+        progress.done,
+        progress.total,
+    )
+}
+
+fn view_rate_limit_budget(model: &Model) -> Node<Msg> {
+    let budget = match model.response_cache.borrow().rate_limit {
+        Some(budget) => budget,
+        None => return empty![],
+    };
+    // 残りが1/4を切ったら黄色、1/10を切ったら赤にする
+    let color = if budget.remaining * 10 <= budget.limit {
+        "#d73a49"
+    } else if budget.remaining * 4 <= budget.limit {
+        "#dbab09"
+    } else {
+        "#6a737d"
+    };
+    span![
+        C!["rate-limit-budget"],
+        style! { St::MarginLeft => em(1), St::Color => color },
+        attrs! {
+        At::Title => i18n::rate_limit_budget_hint(model.settings.lang),
+        },
+        format!("API: {}/{}", budget.remaining, budget.limit)
+    ]
+}
+
+fn view_no_pending_reviews(lang: Lang) -> Node<Msg> {
+    div![
+        C!["no-pending-reviews"],
+        style! {
+            St::Padding => em(2),
+            St::TextAlign => "center",
+            St::FontSize => em(1.25),
+        },
+        format!("🎉 {}", i18n::no_pending_reviews(lang))
+    ]
+}
+
+// レビュアーを行、リポジトリを列にした表
+fn view_board(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let repositories = board_repositories(organization, model);
+    let visible = visible_columns(
+        model.board_scroll_left,
+        model.board_viewport_width,
+        repositories.len(),
+    );
+    let leading_width = visible.start as f64 * BOARD_COLUMN_WIDTH_PX;
+    let trailing_width = (repositories.len() - visible.end) as f64 * BOARD_COLUMN_WIDTH_PX;
+    let table_width =
+        BOARD_FIXED_COLUMNS_WIDTH_PX + repositories.len() as f64 * BOARD_COLUMN_WIDTH_PX;
+    let rows = board_rows(organization, &repositories, model);
+    let visible_rows = visible_rows(model, rows.len());
+    let leading_height = visible_rows.start as f64 * model.board_row_height;
+    let trailing_height = (rows.len() - visible_rows.end) as f64 * model.board_row_height;
+    // レビュアー列・合計列・左右の余白の列の分を足す
+    let column_span = visible.len() + 4;
+    div![
+        C!["board-container"],
+        el_ref(&model.board_container),
+        style! { St::OverflowX => "auto" },
+        ev(Ev::Scroll, |_| Msg::BoardScrolled),
+        table![
+            C!["board"],
+            style! {
+                St::TableLayout => "fixed",
+                St::Width => px(table_width),
+            },
+            compact_table_style(model),
+            thead![tr![
+                th![
+                    C!["sortable"],
+                    sticky_column_style("#fff", 0.0, BOARD_REVIEWER_COLUMN_WIDTH_PX),
+                    style! { St::Cursor => "pointer" },
+                    attrs! {
+                    At::Title => i18n::sort_by_load(lang),
+                    },
+                    i18n::reviewers(lang),
+                    match model.settings.load_sort {
+                        Some(SortDirection::Descending) => " ▼",
+                        Some(SortDirection::Ascending) => " ▲",
+                        None => "",
+                    },
+                    ev(Ev::Click, |_| Msg::SortByLoad),
+                ],
+                th![
+                    sticky_column_style(
+                        "#fff",
+                        BOARD_REVIEWER_COLUMN_WIDTH_PX,
+                        BOARD_TOTAL_COLUMN_WIDTH_PX
+                    ),
+                    i18n::total(lang)
+                ],
+                view_spacer_cell(th![], leading_width),
+                repositories[visible.clone()].iter().map(|repository| {
+                    th![
+                        style! { St::Width => px(BOARD_COLUMN_WIDTH_PX) },
+                        organization.repository_label(repository),
+                        div![
+                            C!["repository-total"],
+                            style! { St::FontWeight => "normal", St::Color => "#6a737d" },
+                            attrs! {
+                            At::Title => i18n::assigned_pull_requests(lang),
+                            },
+                            repository.assigned_pull_request_count(),
+                        ],
+                        view_attention_badge(
+                            repository,
+                            model.attention_repository.as_ref() == Some(&repository.full_name),
+                            model,
+                        ),
+                    ]
+                }),
+                view_spacer_cell(th![], trailing_width),
+            ]],
+            tbody![
+                view_spacer_row(leading_height, column_span),
+                rows.into_iter()
+                    .skip(visible_rows.start)
+                    .take(visible_rows.len())
+                    .map(|row| {
+                        let is_breaching = row.cells.iter().flatten().any(|pull_request| {
+                            breaches_sla(pull_request, model.settings.sla_hours)
+                        });
+                        let is_unassigned = row.login == UNASSIGNED_REVIEWER;
+                        let is_me = model.viewer_login.as_deref() == Some(row.login);
+                        let row_background = if is_breaching {
+                            "#fff5f5"
+                        } else if is_me {
+                            "#f1f8ff"
+                        } else if is_unassigned {
+                            "#fff5e6"
+                        } else {
+                            "#fff"
+                        };
+                        tr![
+                            C![
+                                "reviewer",
+                                IF!(is_breaching => "sla-breached"),
+                                IF!(is_unassigned => "unassigned"),
+                                IF!(is_me => "me")
+                            ],
+                            style! { St::BackgroundColor => row_background },
+                            IF!(is_me => style! { St::FontWeight => "bold" }),
+                            th![
+                                sticky_column_style(
+                                    row_background,
+                                    0.0,
+                                    BOARD_REVIEWER_COLUMN_WIDTH_PX
+                                ),
+                                if is_unassigned {
+                                    span![
+                                        style! { St::Color => "#e36209" },
+                                        attrs! {
+                                        At::Title => i18n::unassigned_hint(lang),
+                                        },
+                                        organization
+                                            .reviewer_label(row.owner, i18n::unassigned(lang))
+                                    ]
+                                } else {
+                                    a![
+                                        attrs! {
+                                        At::Href => reviewer_profile_url(row.owner, row.login),
+                                        At::Target => "_blank",
+                                        At::Rel => "noopener noreferrer",
+                                        },
+                                        organization.reviewer_label(row.owner, row.login)
+                                    ]
+                                }
+                            ],
+                            view_total_cell(&row, row_background, model),
+                            view_spacer_cell(td![], leading_width),
+                            repositories[visible.clone()]
+                                .iter()
+                                .zip(&row.cells[visible.clone()])
+                                .map(|(repository, pull_requests)| {
+                                    let reviewer = repository.reviewer(row.login);
+                                    td![
+                                        C!["pull-requests"],
+                                        style! { St::OverflowWrap => "anywhere" },
+                                        compact_cell_style(model),
+                                        pull_requests.iter().map(|pull_request| {
+                                            view_pull_request_chip(pull_request, reviewer, model)
+                                        }),
+                                    ]
+                                }),
+                            view_spacer_cell(td![], trailing_width),
+                        ]
+                    }),
+                view_spacer_row(trailing_height, column_span),
+            ],
+        ],
+    ]
+}
+
+// リポジトリを行、レビュアーを列にした表。絞り込みや並び順はレビュアーごとの表と同じものを使う
+fn view_board_by_repository(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let repositories = board_repositories(organization, model);
+    let rows = board_rows(organization, &repositories, model);
+    div![
+        C!["board-container"],
+        style! { St::OverflowX => "auto" },
+        table![
+            C!["board", "by-repository"],
+            compact_table_style(model),
+            thead![tr![
+                th![
+                    sticky_column_style("#fff", 0.0, BOARD_REVIEWER_COLUMN_WIDTH_PX),
+                    i18n::repository(lang)
+                ],
+                rows.iter().map(|row| {
+                    let is_unassigned = row.login == UNASSIGNED_REVIEWER;
+                    th![
+                        style! { St::MinWidth => px(BOARD_COLUMN_WIDTH_PX) },
+                        IF!(model.viewer_login.as_deref() == Some(row.login) => C!["me"]),
+                        if is_unassigned {
+                            span![
+                                style! { St::Color => "#e36209" },
+                                organization.reviewer_label(row.owner, i18n::unassigned(lang))
+                            ]
+                        } else {
+                            a![
+                                attrs! {
+                                At::Href => reviewer_profile_url(row.owner, row.login),
+                                At::Target => "_blank",
+                                At::Rel => "noopener noreferrer",
+                                },
+                                organization.reviewer_label(row.owner, row.login)
+                            ]
+                        },
+                        div![
+                            C!["reviewer-total"],
+                            style! { St::FontWeight => "normal", St::Color => "#6a737d" },
+                            row.load(),
+                        ],
+                    ]
+                }),
+            ]],
+            tbody![repositories.iter().enumerate().map(|(index, repository)| {
+                tr![
+                    C!["repository"],
+                    th![
+                        sticky_column_style("#fff", 0.0, BOARD_REVIEWER_COLUMN_WIDTH_PX),
+                        organization.repository_label(repository),
+                        div![
+                            C!["repository-total"],
+                            style! { St::FontWeight => "normal", St::Color => "#6a737d" },
+                            attrs! {
+                            At::Title => i18n::assigned_pull_requests(lang),
+                            },
+                            repository.assigned_pull_request_count(),
+                        ],
+                    ],
+                    rows.iter().map(|row| {
+                        let reviewer = repository.reviewer(row.login);
+                        td![
+                            C!["pull-requests"],
+                            style! { St::OverflowWrap => "anywhere" },
+                            compact_cell_style(model),
+                            row.cells[index].iter().map(|pull_request| {
+                                view_pull_request_chip(pull_request, reviewer, model)
+                            }),
+                        ]
+                    }),
+                ]
+            })],
+        ],
+    ]
+}
+
+// チームのレビュアーは "@slug" として持っているので、Organizationのチームページに飛ばす
+fn reviewer_profile_url(owner: &str, login: &str) -> String {
+    match login.strip_prefix('@') {
+        Some(slug) => format!("https://github.com/orgs/{}/teams/{}", owner, slug),
+        None => format!("https://github.com/{}", login),
+    }
+}
+
+// 表の列にするリポジトリ。要対応バッジで絞り込み中はそのリポジトリだけ
+fn board_repositories<'a>(organization: &'a Organization, model: &Model) -> Vec<&'a Repository> {
+    let mut repositories: Vec<&Repository> = organization
+        .repositories
+        .iter()
+        .filter(|repository| match &model.attention_repository {
+            Some(full_name) => &repository.full_name == full_name,
+            None => true,
+        })
+        .filter(|repository| match &model.selected_repository {
+            Some(full_name) => &repository.full_name == full_name,
+            None => true,
+        })
+        .collect();
+    // sort_by_keyは安定ソートなので、同じ順位のリポジトリはAPIの順のまま並ぶ
+    match model.settings.repository_sort {
+        RepositorySort::ApiOrder => {}
+        RepositorySort::Alphabetical => repositories
+            .sort_by_key(|repository| organization.repository_label(repository).to_lowercase()),
+        RepositorySort::ByPullRequestCount => repositories
+            .sort_by_key(|repository| std::cmp::Reverse(repository.assigned_pull_request_count())),
+    }
+    repositories
+}
+
+struct BoardRow<'a> {
+    owner: &'a str,
+    login: &'a str,
+    // 列ごとに表示するPR
+    cells: Vec<Vec<&'a PullRequest>>,
+    weighted_load: f64,
+}
+
+impl BoardRow<'_> {
+    // クローズ済みのPRはチップとして見せるだけで、負荷には数えない
+    fn load(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|pull_request| !pull_request.closed)
+            .count()
+    }
+}
+
+// 表の行。各レビュアーについて、列ごとに表示するPRを返す。表示するPRがない行と、PRがmin_prs件に満たない行は除く
+fn board_rows<'a>(
+    organization: &'a Organization,
+    repositories: &[&'a Repository],
+    model: &Model,
+) -> Vec<BoardRow<'a>> {
+    let attention_only = model.attention_repository.is_some();
+    let reviewer_filter = model.reviewer_filter.trim().to_lowercase();
+    let settings = &model.settings;
+    let mut keys = organization.reviewer_keys();
+    // 担当PRのないメンバーの行も出す。要対応のPRだけに絞り込んでいるときは、PRのない行は意味がないので出さない
+    let show_empty_rows = settings.show_all_members && !attention_only;
+    if show_empty_rows {
+        for (owner, login) in &organization.members {
+            let key = (owner.as_str(), login.as_str());
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    let mut rows: Vec<BoardRow> = keys
+        .into_iter()
+        .filter(|(_, login)| login.to_lowercase().contains(&reviewer_filter))
+        .filter_map(|(owner, login)| {
+            let mut weighted_load = 0.0;
+            let cells: Vec<Vec<&PullRequest>> = repositories
+                .iter()
+                .map(|repository| {
+                    if repository.owner() != owner {
+                        return Vec::new();
+                    }
+                    let reviewer = match repository.reviewer(login) {
+                        Some(reviewer) => reviewer,
+                        None => return Vec::new(),
+                    };
+                    let mut pull_requests: Vec<&PullRequest> = repository
+                        .assigned_pull_requests(reviewer)
+                        .filter(|pull_request| {
+                            !attention_only
+                                || needs_attention(pull_request, model.settings.stale_days)
+                        })
+                        .filter(|pull_request| {
+                            !(model.settings.hide_self_reviews && pull_request.author == login)
+                        })
+                        .collect();
+                    // created_atはISO 8601（UTC）なので、文字列の順がそのまま時刻の順になる
+                    match model.settings.chip_sort {
+                        ChipSort::Oldest => {
+                            pull_requests.sort_by(|a, b| a.created_at.cmp(&b.created_at))
+                        }
+                        ChipSort::Number => {
+                            pull_requests.sort_by_key(|pull_request| pull_request.number)
+                        }
+                    }
+                    weighted_load += pull_requests
+                        .iter()
+                        .filter(|pull_request| !pull_request.closed)
+                        .map(|pull_request| {
+                            reviewer.load_weight(pull_request, &model.settings.load_weights)
+                        })
+                        .sum::<f64>();
+                    pull_requests
+                })
+                .collect();
+            let row = BoardRow {
+                owner,
+                login,
+                cells,
+                weighted_load,
+            };
+            let is_empty = row.cells.iter().all(Vec::is_empty);
+            if (!show_empty_rows && is_empty) || row.load() < model.min_prs {
+                None
+            } else {
+                Some(row)
+            }
+        })
+        .collect();
+    if let Some(direction) = model.settings.load_sort {
+        // 同じ負荷なら元の並び順のままにする
+        rows.sort_by(|a, b| {
+            let ordering = if model.settings.sort_by_weighted_load {
+                a.weighted_load.total_cmp(&b.weighted_load)
+            } else {
+                a.load().cmp(&b.load())
+            };
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    rows
+}
+
+// Compactのときだけ表と各セル・チップに重ねるスタイル。Comfortableはブラウザの既定のまま
+fn compact_table_style(model: &Model) -> Option<Style> {
+    (model.settings.density == Density::Compact).then(|| style! { St::FontSize => "85%" })
+}
+
+fn compact_cell_style(model: &Model) -> Option<Style> {
+    (model.settings.density == Density::Compact).then(|| style! { St::Padding => 0 })
+}
+
+fn compact_chip_style(model: &Model) -> Option<Style> {
+    (model.settings.density == Density::Compact).then(|| style! { St::LineHeight => 1.2 })
+}
+
+// 横スクロールしてもレビュアー列と合計列が見えたままになるようにする
+fn sticky_column_style(background: &str, left: f64, width: f64) -> Style {
+    style! {
+        St::Position => "sticky",
+        St::Left => px(left),
+        St::ZIndex => 1,
+        St::Width => px(width),
+        St::BackgroundColor => background,
+    }
+}
+
+// 担当数と上限を "3/5" の形で表示し、上限に対する負荷で色分けする
+fn view_total_cell(row: &BoardRow, background: &str, model: &Model) -> Node<Msg> {
+    let load = row.load();
+    let capacity = model
+        .capacities
+        .get(row.owner)
+        .and_then(|capacities| capacities.get(row.login))
+        .copied();
+    let color = match capacity {
+        Some(capacity) => Some(capacity_color(load, capacity)),
+        None if load > model.settings.busy_threshold as usize => Some("#d73a49"),
+        None => None,
+    };
+    let owner = row.owner.to_string();
+    let login = row.login.to_string();
+    let (open_all_owner, open_all_login) = (owner.clone(), login.clone());
+    td![
+        C!["total"],
+        sticky_column_style(
+            background,
+            BOARD_REVIEWER_COLUMN_WIDTH_PX,
+            BOARD_TOTAL_COLUMN_WIDTH_PX
+        ),
+        span![
+            style! {
+                St::Color => color.unwrap_or("inherit"),
+                St::FontWeight => "bold",
+            },
+            match capacity {
+                Some(capacity) => format!("{}/{}", load, capacity),
+                None => load.to_string(),
+            }
+        ],
+        // 重みが既定のままならスコアは件数と同じなので出さない
+        IF!(model.settings.load_weights != LoadWeights::default() => span![
+            C!["weighted-load"],
+            style! { St::MarginLeft => em(0.5) },
+            attrs! {
+            At::Title => i18n::weighted_load(model.settings.lang),
+            },
+            format!("({:.1})", row.weighted_load)
+        ]),
+        // レビュアーなしの行は人ではないので、上限は設定できないようにする
+        IF!(!model.kiosk && row.login != UNASSIGNED_REVIEWER => input![
+            C!["capacity"],
+            style! { St::Width => em(3.5), St::MarginLeft => em(0.5) },
+            attrs! {
+            At::Type => "number",
+            At::Min => 0,
+            At::Placeholder => i18n::capacity(model.settings.lang),
+            At::Title => i18n::capacity_hint(model.settings.lang),
+            At::Value => capacity.map(|capacity| capacity.to_string()).unwrap_or_default(),
+            },
+            input_ev(Ev::Change, move |value| Msg::ChangeCapacity(owner, login, value)),
+        ]),
+        IF!(!model.kiosk => button![
+            C!["open-all"],
+            style! { St::MarginLeft => em(0.5) },
+            attrs! {
+            At::Title => i18n::open_all_hint(model.settings.lang),
+            },
+            i18n::open_all(model.settings.lang),
+            ev(Ev::Click, move |_| Msg::OpenAll(open_all_owner, open_all_login)),
+        ]),
+    ]
+}
+
+fn capacity_color(load: usize, capacity: u32) -> &'static str {
+    let capacity = capacity as usize;
+    if load > capacity {
+        "#d73a49"
+    } else if load * 5 >= capacity * 4 {
+        // 上限の8割以上は上限間近とする
+        "#dbab09"
+    } else {
+        "#28a745"
+    }
+}
+
+// 描画を省いた列の幅を埋めて、スクロール幅が変わらないようにする
+fn view_spacer_cell(mut cell: Node<Msg>, width: f64) -> Node<Msg> {
+    if width <= 0.0 {
+        return empty![];
+    }
+    cell.add_style(St::Width, px(width));
+    cell
+}
+
+// 描画を省いた行の高さを埋めて、スクロールバーの長さと位置が変わらないようにする
+fn view_spacer_row(height: f64, column_span: usize) -> Node<Msg> {
+    if height <= 0.0 {
+        return empty![];
+    }
+    tr![
+        C!["spacer"],
+        td![
+            attrs! { At::ColSpan => column_span },
+            style! { St::Height => px(height), St::Padding => 0 },
+        ]
+    ]
+}
+
+fn view_help(lang: Lang) -> Node<Msg> {
+    let shortcuts = [
+        ("r", i18n::shortcut_refresh(lang)),
+        ("/", i18n::shortcut_filter_reviewers(lang)),
+        ("?", i18n::shortcut_help(lang)),
+    ];
+    div![
+        C!["help"],
+        style! {
+            St::Position => "fixed",
+            St::Top => em(1),
+            St::Right => em(1),
+            St::ZIndex => 10,
+            St::Padding => "0.5em 1em",
+            St::BackgroundColor => "#fff",
+            St::Border => "1px solid #d1d5da",
+            St::BorderRadius => px(6),
+            St::BoxShadow => "0 4px 12px rgba(0, 0, 0, 0.15)",
+        },
+        button![
+            style! {
+                St::Float => "right",
+                St::Border => "none",
+                St::Background => "none",
+                St::Cursor => "pointer",
+            },
+            attrs! {
+            At::Title => i18n::dismiss(lang),
+            },
+            "×",
+            ev(Ev::Click, |_| Msg::ToggleHelp)
+        ],
+        h2![
+            style! { St::FontSize => "100%" },
+            i18n::keyboard_shortcuts(lang)
+        ],
+        table![shortcuts.iter().map(|(key, description)| tr![
+            td![kbd![key]],
+            td![style! { St::PaddingLeft => em(1) }, description],
+        ])],
+    ]
+}
+
+fn view_reviewer_filter(model: &Model) -> Node<Msg> {
+    input![
+        C!["reviewer-filter"],
+        el_ref(&model.reviewer_filter_field),
+        attrs! {
+        At::Type => "search",
+        At::Placeholder => i18n::filter_reviewers(model.settings.lang),
+        At::Value => model.reviewer_filter_input,
+        },
+        input_ev(Ev::Input, Msg::FilterReviewers),
+    ]
+}
+
+fn view_min_prs_input(model: &Model) -> Node<Msg> {
+    label![
+        C!["min-prs"],
+        style! { St::MarginLeft => em(0.5) },
+        i18n::min_prs(model.settings.lang),
+        input![
+            style! { St::Width => em(4) },
+            attrs! {
+            At::Type => "number",
+            At::Min => 0,
+            At::Value => model.min_prs,
+            },
+            input_ev(Ev::Input, Msg::ChangeMinPrs),
+        ],
+    ]
+}
+
+fn view_my_reviews(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let login = match &model.viewer_login {
+        Some(login) => login,
+        None => return empty![],
+    };
+    let has_reviews = organization.repositories.iter().any(|repository| {
+        repository
+            .reviewer(login)
+            .is_some_and(|reviewer| !reviewer.assigned_pull_request_ids.is_empty())
+    });
+    if has_reviews {
+        button![
+            C!["scroll-to-me"],
+            i18n::scroll_to_me(lang),
+            ev(Ev::Click, |_| Msg::ScrollToMyRow),
+        ]
+    } else {
+        span![
+            C!["no-reviews-for-me"],
+            style! { St::Color => "#6a737d" },
+            i18n::no_reviews_for_me(lang, login)
+        ]
+    }
+}
+
+fn view_repository_select(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    label![
+        C!["repository-select"],
+        style! { St::MarginLeft => em(1) },
+        i18n::repository(lang),
+        select![
+            option![
+                attrs! {
+                At::Value => "",
+                At::Selected => model.selected_repository.is_none().as_at_value(),
+                },
+                i18n::all_repositories(lang),
+            ],
+            organization.repositories.iter().map(|repository| {
+                option![
+                    attrs! {
+                    At::Value => repository.full_name,
+                    At::Selected => (model.selected_repository.as_ref() == Some(&repository.full_name))
+                        .as_at_value(),
+                    },
+                    organization.repository_label(repository),
+                ]
+            }),
+            input_ev(Ev::Change, |full_name| Msg::SelectRepository(
+                Some(full_name).filter(|full_name| !full_name.is_empty())
+            )),
+        ],
+        select![
+            C!["repository-sort"],
+            style! { St::MarginLeft => em(0.5) },
+            RepositorySort::ALL.iter().map(|&repository_sort| {
+                option![
+                    attrs! {
+                    At::Value => repository_sort.key(),
+                    At::Selected => (model.settings.repository_sort == repository_sort).as_at_value(),
+                    },
+                    i18n::repository_sort(lang, repository_sort),
+                ]
+            }),
+            input_ev(Ev::Change, Msg::ChangeRepositorySort),
+        ],
+        select![
+            C!["chip-sort"],
+            style! { St::MarginLeft => em(0.5) },
+            ChipSort::ALL.iter().map(|&chip_sort| {
+                option![
+                    attrs! {
+                    At::Value => chip_sort.key(),
+                    At::Selected => (model.settings.chip_sort == chip_sort).as_at_value(),
+                    },
+                    i18n::chip_sort(lang, chip_sort),
+                ]
+            }),
+            input_ev(Ev::Change, Msg::ChangeChipSort),
+        ],
+        select![
+            C!["layout"],
+            style! { St::MarginLeft => em(0.5) },
+            Layout::ALL.iter().map(|&layout| {
+                option![
+                    attrs! {
+                    At::Value => layout.key(),
+                    At::Selected => (model.layout == layout).as_at_value(),
+                    },
+                    i18n::layout(lang, layout),
+                ]
+            }),
+            input_ev(Ev::Change, Msg::ChangeLayout),
+        ],
+    ]
+}
+
+fn view_exports(model: &Model) -> Node<Msg> {
+    div![
+        C!["exports"],
+        button![
+            i18n::copy_plain_table(model.settings.lang),
+            attrs! { At::Disabled => model.organization.is_none().as_at_value() },
+            ev(Ev::Click, |_| Msg::CopyPlainTable),
+        ],
+        button![
+            i18n::copy_markdown(model.settings.lang),
+            attrs! { At::Disabled => model.organization.is_none().as_at_value() },
+            ev(Ev::Click, |_| Msg::CopyMarkdown),
+        ],
+        button![
+            i18n::export_csv(model.settings.lang),
+            attrs! { At::Disabled => model.organization.is_none().as_at_value() },
+            ev(Ev::Click, |_| Msg::ExportCsv),
+        ],
+        IF!(model.copy_failed => span![
+            C!["copy-failed"],
+            style! { St::Color => "#d73a49" },
+            i18n::copy_failed(model.settings.lang)
+        ]),
+        IF!(model.download_failed => span![
+            C!["download-failed"],
+            style! { St::Color => "#d73a49" },
+            i18n::download_failed(model.settings.lang)
+        ]),
+    ]
+}
+
+fn view_attention_badge(repository: &Repository, is_active: bool, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let attention_count = repository.attention_count(model.settings.stale_days);
+    if attention_count == 0 {
+        return empty![];
+    }
+    if model.kiosk {
+        return span![C!["attention-badge"], attention_count];
+    }
+    let full_name = repository.full_name.clone();
+    button![
+        C!["attention-badge", IF!(is_active => "active")],
+        attrs! {
+        At::Title => i18n::needs_attention(lang, attention_count, model.settings.stale_days),
+        },
+        attention_count,
+        ev(Ev::Click, move |_| Msg::ToggleAttentionFilter(full_name)),
+    ]
+}
+
+fn view_sla_summary(organization: &Organization, model: &Model) -> Node<Msg> {
+    let breaching_count = organization
+        .repositories
+        .iter()
+        .flat_map(|repository| &repository.pull_requests)
+        .filter(|pull_request| breaches_sla(pull_request, model.settings.sla_hours))
+        .count();
+    if breaching_count == 0 {
+        return empty![];
+    }
+    p![
+        C!["sla-summary"],
+        style! { St::Color => "#d73a49" },
+        i18n::sla_breaching(
+            model.settings.lang,
+            breaching_count,
+            model.settings.sla_hours
+        )
+    ]
+}
+
+fn view_self_review_note(organization: &Organization, model: &Model) -> Node<Msg> {
+    if !model.settings.hide_self_reviews {
+        return empty![];
+    }
+    let suppressed_count = organization
+        .repositories
+        .iter()
+        .flat_map(|repository| {
+            repository.reviewers.values().map(move |reviewer| {
+                repository
+                    .assigned_pull_requests(reviewer)
+                    .filter(|pull_request| pull_request.author == reviewer.name)
+                    .count()
+            })
+        })
+        .sum::<usize>();
+    if suppressed_count == 0 {
+        return empty![];
+    }
+    p![
+        C!["self-review-note"],
+        i18n::self_reviews_hidden(model.settings.lang, suppressed_count)
+    ]
+}
+
+fn view_fetch_warnings(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    if organization.warnings.is_empty() {
+        return empty![];
+    }
+    div![
+        C!["fetch-warnings"],
+        style! { St::Color => "#b08800" },
+        p![
+            i18n::some_requests_failed(lang, organization.warnings.len()),
+            IF!(!model.kiosk => button![
+                style! { St::MarginLeft => em(1) },
+                i18n::dismiss(lang),
+                ev(Ev::Click, |_| Msg::DismissFetchWarnings)
+            ]),
+        ],
+        ul![organization
+            .warnings
+            .iter()
+            .map(|warning| li![warning.message(lang)])],
+    ]
+}
+
+fn view_skipped_repositories_note(model: &Model) -> Node<Msg> {
+    if model.skipped_repository_count == 0 {
+        return empty![];
+    }
+    p![
+        C!["skipped-repositories-note"],
+        i18n::repositories_skipped(
+            model.settings.lang,
+            model.skipped_repository_count,
+            model.settings.recent_days
+        )
+    ]
+}
+
+fn view_organization_select(model: &Model) -> Node<Msg> {
+    let organizations = match &model.user_organizations {
+        Some(organizations) if !organizations.is_empty() => organizations,
+        _ => return empty![],
+    };
+    let is_listed = organizations.contains(&model.form.organization);
+    select![
+        C!["organization-select"],
+        option![
+            attrs! {
+            At::Value => "",
+            At::Disabled => true.as_at_value(),
+            At::Selected => (!is_listed).as_at_value(),
+            },
+            i18n::switch_organization(model.settings.lang),
+        ],
+        organizations.iter().map(|organization| {
+            option![
+                attrs! {
+                At::Value => organization,
+                At::Selected => (organization == &model.form.organization).as_at_value(),
+                },
+                organization,
+            ]
+        }),
+        input_ev(Ev::Change, Msg::SelectOrg),
+    ]
+}
+
+fn view_credentials_form(model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    // Enterで送信できるようにformにする。ページ遷移はさせずにSubmitClickedとして扱う
+    form![
+        C!["credentials"],
+        ev(Ev::Submit, |event| {
+            event.prevent_default();
+            Msg::SubmitClicked
+        }),
+        input![
+            attrs! {
+            At::Type => "text",
+            At::Placeholder => i18n::organization(lang),
+            At::Title => i18n::organization_hint(lang),
+            At::Value => model.form.organization,
+            At::List => "organization-history",
+            },
+            input_ev(Ev::Input, Msg::OrganizationChanged),
+        ],
+        datalist![
+            attrs! { At::Id => "organization-history" },
+            model
+                .organization_history
+                .iter()
+                .map(|organization| option![attrs! { At::Value => organization }]),
+        ],
+        view_organization_select(model),
+        input![
+            C!["team-slug"],
+            attrs! {
+            At::Type => "text",
+            At::Placeholder => i18n::team_slug(lang),
+            At::Title => i18n::team_slug_hint(lang),
+            At::Value => model.form.team_slug,
+            },
+            input_ev(Ev::Input, Msg::TeamSlugChanged),
+        ],
+        input![
+            C!["repo-prefix"],
+            attrs! {
+            At::Type => "text",
+            At::Placeholder => i18n::repo_prefix(lang),
+            At::Title => i18n::repo_prefix_hint(lang),
+            At::Value => model.form.repo_prefix,
+            },
+            input_ev(Ev::Input, Msg::RepoPrefixChanged),
+        ],
+        input![
+            el_ref(&model.token_input),
+            attrs! {
+            At::Type => if model.show_token { "text" } else { "password" },
+            At::Placeholder => i18n::personal_access_token(lang),
+            At::Value => model.form.token,
+            },
+            IF!(model.token_expired => style! { St::Border => "2px solid #d73a49" }),
+            input_ev(Ev::Input, Msg::TokenChanged),
+        ],
+        button![
+            C!["token-visibility"],
+            attrs! { At::Type => "button" },
+            if model.show_token {
+                i18n::hide_token(lang)
+            } else {
+                i18n::show_token(lang)
+            },
+            ev(Ev::Click, |_| Msg::ToggleTokenVisibility)
+        ],
+        button![attrs! { At::Type => "submit" }, i18n::fetch_data(lang)],
+        button![
+            C!["clear-credentials"],
+            attrs! { At::Type => "button" },
+            i18n::clear_saved_credentials(lang),
+            ev(Ev::Click, |_| Msg::ClearCredentials)
+        ],
+        IF!(model.organization_missing => p![
+            C!["organization-missing"],
+            style! { St::Color => "#d73a49" },
+            i18n::organization_required(lang)
+        ]),
+        IF!(model.token_format_warning => p![
+            C!["token-format-warning"],
+            style! { St::Color => "#b08800" },
+            i18n::unexpected_token_format(lang)
+        ]),
+        IF!(model.token_expired => p![
+            C!["token-expired"],
+            style! { St::Color => "#d73a49" },
+            i18n::token_expired(lang)
+        ]),
+    ]
+}
+
+fn view_settings(model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    div![
+        C!["settings"],
+        view_lang_switcher(lang),
+        view_refresh_interval_select(model),
+        label![
+            C!["sla-hours"],
+            i18n::review_sla_hours(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 1,
+                At::Value => model.settings.sla_hours,
+                },
+                input_ev(Ev::Input, Msg::ChangeSlaHours),
+            ],
+        ],
+        label![
+            C!["stale-days"],
+            i18n::stale_days(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 1,
+                At::Value => model.settings.stale_days,
+                },
+                input_ev(Ev::Input, Msg::ChangeStaleDays),
+            ],
+        ],
+        label![
+            C!["compact"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => (model.settings.density == Density::Compact).as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleCompact),
+            ],
+            i18n::compact_view(lang),
+        ],
+        label![
+            C!["hide-self-reviews"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.hide_self_reviews.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleHideSelfReviews),
+            ],
+            i18n::hide_self_reviews(lang),
+        ],
+        label![
+            C!["hide-bots"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.hide_bots.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleHideBots),
+            ],
+            i18n::hide_bots(lang),
+        ],
+        label![
+            C!["extra-bot-logins"],
+            attrs! {
+            At::Title => i18n::extra_bot_logins_hint(lang),
+            },
+            i18n::extra_bot_logins(lang),
+            input![
+                attrs! {
+                At::Type => "text",
+                At::Value => model.settings.extra_bot_logins,
+                At::Placeholder => "deploy-bot, ci-user",
+                At::Disabled => (!model.settings.hide_bots).as_at_value(),
+                },
+                input_ev(Ev::Input, Msg::ChangeExtraBotLogins),
+            ],
+        ],
+        label![
+            C!["include-archived"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.include_archived.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleIncludeArchived),
+            ],
+            i18n::include_archived(lang),
+        ],
+        label![
+            C!["fetch-reviews"],
+            attrs! {
+            At::Title => i18n::fetch_reviews_hint(lang),
+            },
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.fetch_reviews.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleFetchReviews),
+            ],
+            i18n::fetch_reviews(lang),
+        ],
+        label![
+            C!["use-assignees"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.use_assignees.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleUseAssignees),
+            ],
+            i18n::use_assignees(lang),
+        ],
+        label![
+            C!["fetch-mergeable"],
+            attrs! {
+            At::Title => i18n::fetch_mergeable_hint(lang),
+            },
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.fetch_mergeable.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleFetchMergeable),
+            ],
+            i18n::fetch_mergeable(lang),
+        ],
+        label![
+            C!["fetch-ci-status"],
+            attrs! {
+            At::Title => i18n::fetch_ci_status_hint(lang),
+            },
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.fetch_ci_status.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleFetchCiStatus),
+            ],
+            i18n::fetch_ci_status(lang),
+        ],
+        label![
+            C!["show-all-members"],
+            attrs! {
+            At::Title => i18n::show_all_members_hint(lang),
+            },
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.show_all_members.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleShowAllMembers),
+            ],
+            i18n::show_all_members(lang),
+        ],
+        label![
+            C!["pull-request-state"],
+            i18n::pull_request_state(lang),
+            select![
+                PullRequestState::ALL.iter().map(|&pull_request_state| {
+                    option![
+                        attrs! {
+                        At::Value => pull_request_state.key(),
+                        At::Selected => (model.settings.pull_request_state == pull_request_state)
+                            .as_at_value(),
+                        },
+                        i18n::pull_request_state_option(lang, pull_request_state),
+                    ]
+                }),
+                input_ev(Ev::Change, Msg::ChangePullRequestState),
+            ],
+        ],
+        label![
+            C!["recent-only"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.recent_only.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleRecentOnly),
+            ],
+            i18n::recent_only_before_days(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 1,
+                At::Value => model.settings.recent_days,
+                At::Disabled => (!model.settings.recent_only).as_at_value(),
+                },
+                input_ev(Ev::Input, Msg::ChangeRecentDays),
+            ],
+            i18n::recent_only_after_days(lang),
+        ],
+        label![
+            C!["large-organization-threshold"],
+            i18n::large_organization_threshold(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 1,
+                At::Value => model.settings.large_organization_threshold,
+                },
+                input_ev(Ev::Input, Msg::ChangeLargeOrganizationThreshold),
+            ],
+        ],
+        label![
+            C!["throttle"],
+            attrs! {
+            At::Title => i18n::throttle_hint(lang),
+            },
+            i18n::throttle(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 0,
+                At::Step => 100,
+                At::Value => model.settings.throttle_ms,
+                },
+                input_ev(Ev::Input, Msg::ChangeThrottle),
+            ],
+        ],
+        label![
+            C!["busy-threshold"],
+            i18n::busy_threshold(lang),
+            input![
+                attrs! {
+                At::Type => "number",
+                At::Min => 0,
+                At::Value => model.settings.busy_threshold,
+                },
+                input_ev(Ev::Input, Msg::ChangeBusyThreshold),
+            ],
+        ],
+        view_load_weight_input(
+            i18n::draft_weight(lang),
+            model.settings.load_weights.draft,
+            Msg::ChangeDraftWeight
+        ),
+        view_load_weight_input(
+            i18n::re_review_weight(lang),
+            model.settings.load_weights.re_review,
+            Msg::ChangeReReviewWeight
+        ),
+        view_load_weight_input(
+            &i18n::small_weight(lang, SMALL_PULL_REQUEST_MAX_LINES),
+            model.settings.load_weights.small,
+            Msg::ChangeSmallWeight
+        ),
+        view_load_weight_input(
+            &i18n::large_weight(lang, LARGE_PULL_REQUEST_MIN_LINES),
+            model.settings.load_weights.large,
+            Msg::ChangeLargeWeight
+        ),
+        view_load_weight_input(
+            i18n::ci_failure_weight(lang),
+            model.settings.load_weights.ci_failure,
+            Msg::ChangeCiFailureWeight
+        ),
+        label![
+            C!["sort-by-weighted-load"],
+            input![
+                attrs! {
+                At::Type => "checkbox",
+                At::Checked => model.settings.sort_by_weighted_load.as_at_value(),
+                },
+                ev(Ev::Change, |_| Msg::ToggleSortByWeightedLoad),
+            ],
+            i18n::sort_by_weighted_load(lang),
+        ],
+        label![
+            C!["label-color-rules"],
+            attrs! {
+            At::Title => i18n::label_color_rules_hint(lang),
+            },
+            i18n::label_color_rules(lang),
+            input![
+                attrs! {
+                At::Type => "text",
+                At::Placeholder => "urgent=#ffdce0, bug=#fff5b1",
+                At::Value => format_label_color_rules(&model.settings.label_color_rules),
+                },
+                // 入力途中の「urgent=」などが消えないよう、入力し終えてから反映する
+                input_ev(Ev::Change, Msg::ChangeLabelColorRules),
+            ],
+        ],
+    ]
+}
+
+// "urgent=#ffdce0, bug=#fff5b1" の形式。ラベル名には ":" や空白を含むものが多いので "=" で区切る
+fn parse_label_color_rules(value: &str) -> Vec<(String, String)> {
+    value
+        .split(',')
+        .filter_map(|rule| {
+            let (label, color) = rule.split_once('=')?;
+            let (label, color) = (label.trim(), color.trim());
+            (!label.is_empty() && !color.is_empty()).then(|| (label.to_string(), color.to_string()))
+        })
+        .collect()
+}
+
+fn format_label_color_rules(rules: &[(String, String)]) -> String {
+    rules
+        .iter()
+        .map(|(label, color)| format!("{}={}", label, color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn label_color<'a>(pull_request: &PullRequest, rules: &'a [(String, String)]) -> Option<&'a str> {
+    rules
+        .iter()
+        .find(|(label, _)| {
+            pull_request
+                .labels
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(label))
+        })
+        .map(|(_, color)| color.as_str())
+}
+
+fn view_load_weight_input(
+    label: &str,
+    weight: f64,
+    to_msg: impl FnOnce(String) -> Msg + Clone + 'static,
+) -> Node<Msg> {
+    label![
+        C!["load-weight"],
+        label,
+        input![
+            style! { St::Width => em(4) },
+            attrs! {
+            At::Type => "number",
+            At::Min => 0,
+            At::Step => 0.5,
+            At::Value => weight,
+            },
+            input_ev(Ev::Input, to_msg),
+        ],
+    ]
+}
+
+fn view_refresh_interval_select(model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    label![
+        C!["refresh-interval"],
+        i18n::auto_refresh(lang),
+        select![
+            option![
+                attrs! {
+                At::Value => "",
+                At::Selected => model.settings.refresh_secs.is_none().as_at_value(),
+                },
+                i18n::off(lang),
+            ],
+            REFRESH_INTERVAL_OPTIONS.iter().map(|&refresh_secs| {
+                option![
+                    attrs! {
+                    At::Value => refresh_secs,
+                    At::Selected => (model.settings.refresh_secs == Some(refresh_secs)).as_at_value(),
+                    },
+                    i18n::refresh_interval(lang, refresh_secs),
+                ]
+            }),
+            input_ev(Ev::Change, Msg::ChangeRefreshInterval),
+        ],
+    ]
+}
+
+fn view_lang_switcher(lang: Lang) -> Node<Msg> {
+    label![
+        C!["lang-switcher"],
+        i18n::language(lang),
+        select![
+            Lang::ALL.iter().map(|&option| {
+                option![
+                    attrs! {
+                    At::Value => option.code(),
+                    At::Selected => (option == lang).as_at_value(),
+                    },
+                    option.native_name()
+                ]
+            }),
+            input_ev(Ev::Change, |code| Lang::from_code(&code)
+                .map(Msg::ChangeLang)),
+        ],
+    ]
+}
+
+fn view_pull_request_chip(
+    pull_request: &PullRequest,
+    reviewer: Option<&Reviewer>,
+    model: &Model,
+) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let id = pull_request.id;
+    let review_state = reviewer.and_then(|reviewer| reviewer.review_state(id));
+    let is_assignee =
+        reviewer.is_some_and(|reviewer| reviewer.assignee_pull_requests.contains(&id));
+    let is_watched = model.watched_pull_requests.contains(&id);
+    let is_breaching = breaches_sla(pull_request, model.settings.sla_hours);
+    let is_stale = is_stale(pull_request, model.settings.stale_days);
+    span![
+        C![
+            "pull-request",
+            IF!(is_breaching => "sla-breached"),
+            IF!(pull_request.draft => "draft"),
+            IF!(review_state.is_some() => "reviewed"),
+            IF!(is_assignee => "assignee"),
+            IF!(pull_request.closed => "closed")
+        ],
+        compact_chip_style(model),
+        // ドラフトやクローズ済みの見た目のほうを優先するので、それより先に当てる
+        label_color(pull_request, &model.settings.label_color_rules)
+            .map(|color| style! { St::BackgroundColor => color }),
+        IF!(pull_request.closed => style! {
+            St::TextDecoration => "line-through",
+            St::Color => "#6a737d",
+        }),
+        // レビュー済みで依頼の残っていないPRは、未レビューのPRと見分けられるよう斜体にして結果を添える
+        IF!(review_state.is_some() => style! { St::FontStyle => "italic" }),
+        review_state.map(|state| {
+            let (icon, title) = match state {
+                ReviewState::Approved => ("✓", i18n::approved(lang)),
+                ReviewState::ChangesRequested => ("✎", i18n::changes_requested(lang)),
+            };
+            span![
+                C!["review-state"],
+                attrs! { At::Title => title },
+                style! { St::MarginRight => em(0.25) },
+                icon
+            ]
+        }),
+        pull_request.ci_status.map(|ci_status| span![
+            C!["ci-status"],
+            attrs! {
+            At::Title => i18n::ci_status(lang, ci_status),
+            },
+            style! {
+                St::Display => "inline-block",
+                St::Width => em(0.6),
+                St::Height => em(0.6),
+                St::BorderRadius => "50%",
+                St::BackgroundColor => ci_status.color(),
+                St::MarginRight => em(0.25),
+            },
+        ]),
+        IF!(pull_request.mergeable == Some(false) => span![
+            C!["conflict-marker"],
+            attrs! {
+            At::Title => i18n::has_conflicts(lang),
+            },
+            style! { St::MarginRight => em(0.25) },
+            "⚠️"
+        ]),
+        IF!(is_assignee => span![
+            C!["assignee-marker"],
+            attrs! {
+            At::Title => i18n::assignee(lang),
+            },
+            style! { St::MarginRight => em(0.25) },
+            "👤"
+        ]),
+        // ドラフトはまだレビューできる状態ではないので、点線の枠と灰色の背景で控えめにする
+        IF!(pull_request.draft => style! {
+            St::BackgroundColor => "#f6f8fa",
+            St::Color => "#6a737d",
+        }),
+        match (pull_request.draft, is_breaching) {
+            (true, true) => Some(style! { St::Border => "1px dashed #d73a49" }),
+            (true, false) => Some(style! { St::Border => "1px dashed #959da5" }),
+            (false, true) => Some(style! { St::Border => "1px solid #d73a49" }),
+            (false, false) => None,
+        },
+        a![
+            attrs! {
+            At::Href => &pull_request.url,
+            At::Title => i18n::pull_request_tooltip(
+                lang,
+                &pull_request.title,
+                &pull_request.author,
+                &age_label(&pull_request.created_at)
+            ),
+            },
+            format!("#{} {}", pull_request.number, pull_request.title)
+        ],
+        span![
+            C!["age", IF!(is_stale => "stale")],
+            attrs! {
+            At::Title => absolute_time_label(&pull_request.created_at, lang),
+            },
+            style! { St::MarginLeft => em(0.5) },
+            IF!(is_stale => style! {
+                St::Color => "#d73a49",
+                St::FontWeight => "bold",
+            }),
+            age_label(&pull_request.created_at)
+        ],
+        view_labels(&pull_request.labels),
+        IF!(!model.kiosk => view_copy_link_button(&pull_request.url, model)),
+        IF!(!model.kiosk => button![
+            C!["watch-toggle"],
+            attrs! {
+            At::Title => if is_watched {
+                i18n::remove_from_watchlist(lang)
+            } else {
+                i18n::add_to_watchlist(lang)
+            },
+            },
+            if is_watched { "★" } else { "☆" },
+            ev(Ev::Click, move |_| Msg::ToggleWatch(id)),
+        ]),
+    ]
+}
+
+fn view_copy_link_button(url: &str, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let is_copied = model.copied_link.as_deref() == Some(url);
+    let url = url.to_string();
+    button![
+        C!["copy-link"],
+        attrs! {
+        At::Title => i18n::copy_link(lang),
+        },
+        if is_copied {
+            i18n::copied(lang)
+        } else {
+            "🔗"
+        },
+        // チップの中のリンクを開いてしまわないよう、クリックはここで止める
+        ev(Ev::Click, move |event| {
+            event.prevent_default();
+            event.stop_propagation();
+            Msg::CopyLink(url)
+        }),
+    ]
+}
+
+fn view_labels(labels: &[(String, String)]) -> Node<Msg> {
+    if labels.is_empty() {
+        return empty![];
+    }
+    let hidden_labels = labels.get(MAX_CHIP_LABELS..).unwrap_or_default();
+    span![
+        C!["labels"],
+        style! { St::MarginLeft => em(0.5) },
+        labels.iter().take(MAX_CHIP_LABELS).map(|(name, color)| {
+            span![
+                C!["label"],
+                style! {
+                    St::BackgroundColor => format!("#{}", color),
+                    St::Color => label_text_color(color),
+                    St::FontSize => em(0.75),
+                    St::Padding => "0 0.4em",
+                    St::MarginRight => em(0.25),
+                    St::BorderRadius => em(1),
+                    St::WhiteSpace => "nowrap",
+                },
+                name
+            ]
+        }),
+        IF!(!hidden_labels.is_empty() => span![
+            C!["more-labels"],
+            style! { St::FontSize => em(0.75), St::Color => "#6a737d" },
+            attrs! {
+            At::Title => hidden_labels
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            },
+            format!("+{}", hidden_labels.len())
+        ]),
+    ]
+}
+
+// 背景色の明るさに合わせて、読みやすい文字色を選ぶ
+fn label_text_color(color: &str) -> &'static str {
+    let channel = |range| u8::from_str_radix(color.get(range)?, 16).ok();
+    match (channel(0..2), channel(2..4), channel(4..6)) {
+        (Some(r), Some(g), Some(b))
+            if 299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b) < 128_000 =>
+        {
+            "#fff"
+        }
+        _ => "#24292e",
+    }
+}
+
+fn view_watchlist(organization: &Organization, model: &Model) -> Node<Msg> {
+    let lang = model.settings.lang;
+    let watched_pull_requests = &model.watched_pull_requests;
+    if watched_pull_requests.is_empty() {
+        return empty![];
+    }
+    div![
+        C!["watchlist"],
+        h2![i18n::watchlist(lang)],
+        watched_pull_requests
+            .iter()
+            .filter_map(|&id| organization.find_pull_request(id))
+            .map(|pull_request| {
+                let id = pull_request.id;
+                let reviewer_names = organization.reviewer_names_of(id);
+                div![
+                    C!["watched-pull-request"],
+                    a![
+                        attrs! {
+                        At::Href => &pull_request.url,
+                        At::Title => &pull_request.title,
+                        },
+                        format!(
+                            "{}#{} {}",
+                            pull_request.repo_name, pull_request.number, pull_request.title
+                        )
+                    ],
+                    span![
+                        C!["age"],
+                        attrs! {
+                        At::Title => absolute_time_label(&pull_request.created_at, lang),
+                        },
+                        age_label(&pull_request.created_at)
+                    ],
+                    span![
+                        C!["reviewers"],
+                        if reviewer_names.is_empty() {
+                            i18n::no_reviewers_yet(lang).to_string()
+                        } else {
+                            reviewer_names.join(", ")
+                        }
+                    ],
+                    IF!(!model.kiosk => button![
+                        i18n::unwatch(lang),
+                        ev(Ev::Click, move |_| Msg::ToggleWatch(id))
+                    ]),
+                ]
+            }),
+    ]
+}
+
+fn view_large_fetch_confirmation(repository_count: usize, limit: usize, lang: Lang) -> Node<Msg> {
+    div![
+        C!["large-fetch-confirmation"],
+        p![i18n::large_organization_warning(lang, repository_count)],
+        button![
+            i18n::continue_fetch(lang),
+            ev(Ev::Click, |_| Msg::ConfirmLargeFetch(
+                LargeFetchChoice::Continue
+            )),
+        ],
+        button![
+            i18n::limit_to(lang, limit),
+            ev(Ev::Click, move |_| Msg::ConfirmLargeFetch(
+                LargeFetchChoice::Limit(limit)
+            )),
+        ],
+        button![
+            i18n::cancel(lang),
+            ev(Ev::Click, |_| Msg::ConfirmLargeFetch(
+                LargeFetchChoice::Cancel
+            )),
+        ],
     ]
 }